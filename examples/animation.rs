@@ -11,9 +11,10 @@ use lvgl::input_device::{
     InputDriver,
 };
 use lvgl::misc::anim::{AnimRepeatCount, Animation};
+use lvgl::misc::area::Size;
 use lvgl::style::Style;
-use lvgl::widgets::{Btn, Label, Widget};
-use lvgl::{Align, Color, Display, DrawBuffer, LvError, Part};
+use lvgl::widgets::{Btn, Label};
+use lvgl::{Align, Color, Display, DrawBuffer, LvError, NativeObject, Part};
 use std::thread::sleep;
 use std::time::Duration;
 use std::time::Instant;
@@ -46,22 +47,22 @@ fn main() -> Result<(), LvError> {
 
     let mut screen_style = Style::default();
     screen_style.set_bg_color(Color::from_rgb((0, 0, 0)));
-    screen.add_style(screen_style.into_raw(), Part::Main.into());
+    screen.add_style(Part::Main, screen_style.leak());
     // Create the button
     let mut button = Btn::create(&mut screen)?;
-    button.align(Align::LeftMid.into(), 30, 0);
-    button.set_size(180, 80);
+    button.align(Align::LeftMid, 30, 0);
+    button.set_size(Size::Px(180), Size::Px(80));
     let mut btn_lbl = Label::create(&mut button)?;
     btn_lbl.set_text(CString::new("Click me!").unwrap().as_c_str());
 
     let mut btn_state = false;
 
     let mut anim = Animation::new(&mut button, Duration::from_secs(1), 0, 60, |obj, val| {
-        obj.align(Align::LeftMid.into(), val as i16, 0)
+        obj.align(Align::LeftMid, val as i16, 0)
     })?;
     anim.set_repeat_count(AnimRepeatCount::Infinite);
     anim.start();
-    button.on_event(|_btn, event| {
+    button.on_event(move |_btn, event| {
         println!("Button received event: {:?}", event);
         if let lvgl::Event::Clicked = event {
             if btn_state {
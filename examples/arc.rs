@@ -5,9 +5,10 @@ use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use lvgl;
+use lvgl::misc::area::Size;
 use lvgl::style::Style;
-use lvgl::widgets::{Arc, Label, Widget};
-use lvgl::{Align, Color, Display, DrawBuffer, LvError, Part};
+use lvgl::widgets::{Arc, Label};
+use lvgl::{Align, Color, Display, DrawBuffer, LvError, NativeObject, Part};
 use lvgl_sys;
 use std::thread::sleep;
 use std::time::Duration;
@@ -52,23 +53,23 @@ fn main() -> Result<(), LvError> {
     let mut screen_style = Style::default();
     screen_style.set_bg_color(Color::from_rgb((255, 255, 255)));
     screen_style.set_radius(0);
-    screen.add_style(screen_style.into_raw(), Part::Main.into());
+    screen.add_style(Part::Main, screen_style.leak());
 
     // Create the arc object
     let mut arc = Arc::create(&mut screen)?;
-    arc.set_size(150, 150);
-    arc.align(Align::Center.into(), 0, 10);
+    arc.set_size(Size::Px(150), Size::Px(150));
+    arc.align(Align::Center, 0, 10);
     arc.set_start_angle(135);
     arc.set_end_angle(135);
 
     let mut loading_lbl = Label::create(&mut screen)?;
     loading_lbl.set_text(CString::new("Loading...").unwrap().as_c_str());
-    loading_lbl.align(Align::OutTopMid.into(), 0, 0);
+    loading_lbl.align(Align::OutTopMid, 0, 0);
     //loading_lbl.set_label_align(LabelAlign::Center)?;
 
     let mut loading_style = Style::default();
     loading_style.set_text_color(Color::from_rgb((0, 0, 0)));
-    loading_lbl.add_style(loading_style.into_raw(), Part::Main.into());
+    loading_lbl.add_style(Part::Main, loading_style.leak());
 
     let mut angle = 0;
     let mut forward = true;
@@ -5,9 +5,10 @@ use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use lvgl;
+use lvgl::misc::area::Size;
 use lvgl::style::Style;
-use lvgl::widgets::{Bar, Label, Widget};
-use lvgl::{Align, AnimationState, Color, Display, DrawBuffer, Event, LvError, Part};
+use lvgl::widgets::{Bar, Label};
+use lvgl::{Align, AnimEnable, Color, Display, DrawBuffer, Event, LvError, NativeObject, Part};
 use std::thread::sleep;
 use std::time::Duration;
 use std::time::Instant;
@@ -33,12 +34,12 @@ fn main() -> Result<(), LvError> {
     let mut screen_style = Style::default();
     screen_style.set_bg_color(Color::from_rgb((255, 255, 255)));
     screen_style.set_radius(0);
-    screen.add_style(screen_style.into_raw(), Part::Main.into());
+    screen.add_style(Part::Main, screen_style.leak());
 
     // Create the bar object
     let mut bar = Bar::create(&mut screen)?;
-    bar.set_size(175, 20);
-    bar.align(Align::Center.into(), 0, 10);
+    bar.set_size(Size::Px(175), Size::Px(20));
+    bar.align(Align::Center, 0, 10);
     bar.set_range(0, 100);
     bar.on_event(|_b, _e| {
         println!("Completed!");
@@ -47,15 +48,15 @@ fn main() -> Result<(), LvError> {
     // Set the indicator style for the bar object
     let mut ind_style = Style::default();
     ind_style.set_bg_color(Color::from_rgb((100, 245, 100)));
-    bar.add_style(ind_style.into_raw(), Part::Any.into());
+    bar.add_style(Part::Any, ind_style.leak());
 
     let mut loading_lbl = Label::create(&mut screen)?;
     loading_lbl.set_text(CString::new("Loading...").unwrap().as_c_str());
-    loading_lbl.align(Align::OutTopMid.into(), 0, 0);
+    loading_lbl.align(Align::OutTopMid, 0, 0);
 
     let mut loading_style = Style::default();
     loading_style.set_text_color(Color::from_rgb((0, 0, 0)));
-    loading_lbl.add_style(loading_style.into_raw(), Part::Main.into());
+    loading_lbl.add_style(Part::Main, loading_style.leak());
 
     let mut i = 0;
     'running: loop {
@@ -67,7 +68,7 @@ fn main() -> Result<(), LvError> {
             // - implementation of `Widget` is not general enough
             // lvgl::event_send(&mut bar, Event::Clicked);
         }
-        bar.set_value(i, AnimationState::ON.into());
+        bar.set_value(i, AnimEnable::On);
         i += 1;
 
         lvgl::task_handler();
@@ -6,9 +6,10 @@ use embedded_graphics_simulator::{
 };
 use lvgl;
 use lvgl::font::Font;
+use lvgl::misc::area::Size;
 use lvgl::style::Style;
-use lvgl::widgets::{Label, Widget};
-use lvgl::{Align, Color, Display, DrawBuffer, LvError, Part, TextAlign};
+use lvgl::widgets::Label;
+use lvgl::{Align, Color, Display, DrawBuffer, LvError, NativeObject, Part, TextAlign};
 use lvgl_sys;
 use std::thread::sleep;
 use std::time::Duration;
@@ -42,7 +43,7 @@ fn main() -> Result<(), LvError> {
     let mut screen_style = Style::default();
     screen_style.set_bg_color(Color::from_rgb((0, 0, 0)));
     screen_style.set_radius(0);
-    screen.add_style(screen_style.into_raw(), Part::Main.into());
+    screen.add_style(Part::Main, screen_style.leak());
 
     let mut time = Label::from("20:46");
     let mut style_time = Style::default();
@@ -52,22 +53,22 @@ fn main() -> Result<(), LvError> {
     // See font module documentation for an explanation of the unsafe block
     style_time.set_text_font(unsafe { Font::new_raw(lvgl_sys::noto_sans_numeric_80) });
 
-    time.add_style(style_time.into_raw(), Part::Main.into());
-    time.align(Align::Center.into(), 0, 90);
-    time.set_width(240);
-    time.set_height(240);
+    time.add_style(Part::Main, style_time.leak());
+    time.align(Align::Center, 0, 90);
+    time.set_width(Size::Px(240));
+    time.set_height(Size::Px(240));
 
     let mut bt = Label::from("#5794f2 \u{F293}#");
-    bt.set_width(50);
-    bt.set_height(80);
+    bt.set_width(Size::Px(50));
+    bt.set_height(Size::Px(80));
     let _ = bt.set_recolor(true);
-    bt.align(Align::TopLeft.into(), 0, 0);
+    bt.align(Align::TopLeft, 0, 0);
 
     let mut power: Label = "#fade2a 20%#".into();
     let _ = power.set_recolor(true);
-    power.set_width(80);
-    power.set_height(20);
-    power.align(Align::TopRight.into(), 40, 0);
+    power.set_width(Size::Px(80));
+    power.set_height(Size::Px(20));
+    power.align(Align::TopRight, 40, 0);
 
     let mut i = 0;
     'running: loop {
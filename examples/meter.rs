@@ -1,18 +1,16 @@
-// TODO: Redo this example.
-
-/*
 use embedded_graphics::pixelcolor::Rgb565;
 use embedded_graphics::prelude::*;
 use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use lvgl;
-use lvgl::style::{Opacity, Style};
+use lvgl::misc::area::Size;
+use lvgl::style::Style;
 use lvgl::widgets::Meter;
-use lvgl::{
-    Align, Color, Display, DrawBuffer, LvError, Part, State, Widget,
-};
+use lvgl::{Align, Color, Display, DrawBuffer, LvError, NativeObject, Part};
+use std::thread::sleep;
 use std::time::Duration;
+use std::time::Instant;
 
 fn main() -> Result<(), LvError> {
     const HOR_RES: u32 = 240;
@@ -27,45 +25,39 @@ fn main() -> Result<(), LvError> {
     let buffer = DrawBuffer::<{ (HOR_RES * VER_RES) as usize }>::default();
 
     let display = Display::register(buffer, HOR_RES, VER_RES, |refresh| {
-        sim_display
-            .draw_iter(refresh.as_pixels())
-            .unwrap();
+        sim_display.draw_iter(refresh.as_pixels()).unwrap();
     })?;
 
     let mut screen = display.get_scr_act()?;
 
     let mut screen_style = Style::default();
     screen_style.set_bg_color(Color::from_rgb((0, 0, 0)));
-    screen.add_style(Part::Main, &mut screen_style)?;
-
-    // Create the gauge
-    let mut gauge_style = Style::default();
-    // Set a background color and a radius
-    gauge_style.set_radius(5);
-    gauge_style.set_bg_opa(Opacity::OPA_COVER);
-    gauge_style.set_bg_color(Color::from_rgb((192, 192, 192)));
-    // Set some padding's
-    //gauge_style.set_pad_inner(20);
-    gauge_style.set_pad_top(20);
-    gauge_style.set_pad_left(5);
-    gauge_style.set_pad_right(5);
-
-    //gauge_style.set_scale_end_color(Color::from_rgb((255, 0, 0)));
-    gauge_style.set_line_color(Color::from_rgb((255, 255, 255)));
-    //gauge_style.set_scale_grad_color(Color::from_rgb((0, 0, 255)));
-    gauge_style.set_line_width(2);
-    //gauge_style.set_scale_end_line_width(4);
-    //gauge_style.set_scale_end_border_width(4);
-
-    let mut gauge = Gauge::create(&mut screen, None)?;
-    gauge.add_style(Part::Main, &mut gauge_style)?;
-    gauge.set_align(&mut screen, Align::Center, 0, 0)?;
-    gauge.set_value(0, 50)?;
+    screen.add_style(Part::Main, screen_style.leak());
+
+    // Create the meter with a single scale from 0 to 100
+    let mut meter = Meter::create(&mut screen)?;
+    meter.set_size(Size::Px(200), Size::Px(200));
+    meter.align(Align::Center, 0, 0);
+
+    let scale = meter.add_scale().ok_or(LvError::LvOOMemory)?;
+    meter.set_scale_ticks(scale, 41, 2, 10, Color::from_rgb((128, 128, 128)));
+    meter.set_scale_major_ticks(scale, 8, 4, 15, Color::from_rgb((0, 0, 0)), 10);
+    meter.set_scale_range(scale, 0, 100, 270, 135);
+
+    // A red arc over the upper end of the scale, and the needle
+    let arc = meter
+        .add_arc(scale, 3, Color::from_rgb((255, 0, 0)), 0)
+        .ok_or(LvError::LvOOMemory)?;
+    meter.set_indicator_start_value(arc, 80);
+    meter.set_indicator_end_value(arc, 100);
+    let needle = meter
+        .add_needle_line(scale, 4, Color::from_rgb((0, 0, 255)), -10)
+        .ok_or(LvError::LvOOMemory)?;
 
     let mut i = 0;
-    let mut loop_started = Instant::now();
     'running: loop {
-        gauge.set_value(0, i)?;
+        let start = Instant::now();
+        meter.set_indicator_value(needle, i);
 
         lvgl::task_handler();
         window.update(&sim_display);
@@ -83,19 +75,11 @@ fn main() -> Result<(), LvError> {
             }
         }
 
-        if i > 99 {
-            i = 0;
-        } else {
-            i = i + 1;
-        }
+        i = if i > 99 { 0 } else { i + 1 };
 
-        lvgl::tick_inc(Duration::from_millis(16));
+        sleep(Duration::from_millis(15));
+        lvgl::tick_inc(Instant::now().duration_since(start));
     }
 
     Ok(())
 }
-*/
-
-fn main() {
-    println!("Currently broken :c")
-}
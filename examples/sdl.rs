@@ -7,11 +7,11 @@ use cstr_core::CString;
 use lvgl::input_device::InputDriver;
 use lvgl::lv_drv_disp_sdl;
 use lvgl::lv_drv_input_pointer_sdl;
+use lvgl::misc::area::Size;
 use lvgl::style::Style;
-use lvgl::widgets::Widget;
 use lvgl::widgets::{Btn, Label};
 use lvgl::LvResult;
-use lvgl::{Align, Color, DrawBuffer, Part};
+use lvgl::{Align, Color, DrawBuffer, NativeObject, Part};
 use std::thread::sleep;
 use std::time::Duration;
 use std::time::Instant;
@@ -29,16 +29,16 @@ fn main() -> LvResult<()> {
 
     let mut screen_style = Style::default();
     screen_style.set_bg_color(Color::from_rgb((0, 0, 0)));
-    screen.add_style(screen_style.into_raw(), Part::Main.into());
+    screen.add_style(Part::Main, screen_style.leak());
     // Create the button
     let mut button = Btn::create(&mut screen)?;
-    button.align(Align::LeftMid.into(), 30, 0);
-    button.set_size(180, 80);
+    button.align(Align::LeftMid, 30, 0);
+    button.set_size(Size::Px(180), Size::Px(80));
     let mut btn_lbl = Label::create(&mut button)?;
     btn_lbl.set_text(CString::new("Click me!").unwrap().as_c_str());
 
     let mut btn_state = false;
-    button.on_event(|_btn, event| {
+    button.on_event(move |_btn, event| {
         println!("Button received event: {:?}", event);
         if let lvgl::Event::Clicked = event {
             if btn_state {
@@ -66,3 +66,21 @@ pub enum Return {
     /// If the return is a LVGL result
     ResultError(CParameter),
 }
+
+/// Why no wrapper was generated for a C function, see `CodeGen::skipped()`.
+/// Variants caused by a type hold the offending type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SkipReason {
+    /// Returns a pointer to something with no safe wrapper.
+    PointerReturn(String),
+    /// Takes a C array, whose length isn't known.
+    ArrayArgument(String),
+    /// Takes a callback, whose closure would need its own user data.
+    CallbackArgument(String),
+    /// Takes an untyped `void *`.
+    VoidPointerArgument(String),
+    /// Uses a type that can't be written in Rust as is.
+    UnparseableType(String),
+    /// Hidden on purpose, see `CodeGen::with_overrides()`.
+    Hidden,
+}
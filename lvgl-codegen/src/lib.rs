@@ -1,5 +1,7 @@
 mod analysis;
 
+pub use analysis::SkipReason;
+
 use inflector::cases::pascalcase::to_pascal_case;
 use lazy_static::lazy_static;
 use proc_macro2::{Ident, TokenStream};
@@ -8,7 +10,10 @@ use quote::{format_ident, ToTokens};
 use regex::Regex;
 use std::collections::HashMap;
 use std::error::Error;
-use syn::{parse_str, FnArg, ForeignItem, ForeignItemFn, Item, ReturnType, TypePath};
+use syn::{
+    parse_str, Expr, ExprLit, FnArg, ForeignItem, ForeignItemFn, Item, Lit, Meta, MetaNameValue,
+    ReturnType, TypePath,
+};
 
 type CGResult<T> = Result<T, Box<dyn Error>>;
 
@@ -23,15 +28,78 @@ lazy_static! {
         ("u8", "u8"),
         ("i8", "i8"),
         ("bool", "bool"),
+        ("f32", "f32"),
+        ("f64", "f64"),
+        ("usize", "usize"),
+        ("isize", "isize"),
+        ("cty :: c_float", "f32"),
+        ("cty :: c_double", "f64"),
+        ("cty :: size_t", "usize"),
+        ("cty :: ssize_t", "isize"),
     ]
     .iter()
     .cloned()
     .collect();
+
+    // Integer typedefs which look like enums but hold flags or free values, or
+    // are already wrapped by hand in the `lvgl` crate.
+    static ref NON_ENUM_TYPES: Vec<&'static str> = vec![
+        "lv_align_t",
+        "lv_anim_enable_t",
+        "lv_base_dir_t",
+        "lv_blend_mode_t",
+        "lv_border_side_t",
+        "lv_btnmatrix_ctrl_t",
+        "lv_coord_t",
+        "lv_dir_t",
+        "lv_disp_rot_t",
+        "lv_dither_mode_t",
+        "lv_event_code_t",
+        "lv_flex_align_t",
+        "lv_flex_flow_t",
+        "lv_grad_dir_t",
+        "lv_grid_align_t",
+        "lv_label_long_mode_t",
+        "lv_obj_flag_t",
+        "lv_opa_t",
+        "lv_palette_t",
+        "lv_part_t",
+        "lv_state_t",
+        "lv_style_prop_t",
+        "lv_style_selector_t",
+        "lv_table_cell_ctrl_t",
+        "lv_text_align_t",
+        "lv_text_decor_t",
+    ];
+
+    static ref INT_TYPES: Vec<&'static str> = vec![
+        "u8",
+        "u16",
+        "u32",
+        "i8",
+        "i16",
+        "i32",
+        "cty :: c_uchar",
+        "cty :: c_ushort",
+        "cty :: c_uint",
+        "cty :: c_schar",
+        "cty :: c_short",
+        "cty :: c_int",
+    ];
+}
+
+/// Makes an identifier out of a C name, escaping Rust keywords.
+fn to_ident(name: &str) -> Ident {
+    match name {
+        // Keywords which can't be raw identifiers
+        "crate" | "self" | "Self" | "super" => format_ident!("{}_", name),
+        _ => syn::parse_str::<Ident>(name).unwrap_or_else(|_| format_ident!("r#{}", name)),
+    }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub enum WrapperError {
-    Skip,
+    Skip(SkipReason),
 }
 
 pub type WrapperResult<T> = Result<T, WrapperError>;
@@ -46,6 +114,9 @@ pub trait Rusty {
 pub struct LvWidget {
     name: String,
     methods: Vec<LvFunc>,
+    /// Whether to generate `new()` and `Default`, creating the widget on the
+    /// active screen.
+    default_constructor: bool,
 }
 
 impl LvWidget {
@@ -62,37 +133,98 @@ impl Rusty for LvWidget {
         let methods: Vec<TokenStream> = self.methods.iter().flat_map(|m| m.code(self)).collect();
         if self.name.as_str().eq("obj") {
             Ok(quote! {
-                pub trait Widget<'a>: NativeObject + Sized + 'a {
+                pub trait Widget<'a>: CommonWidgetExt + Sized + 'a {
                     type SpecialEvent;
                     type Part: Into<lvgl_sys::lv_part_t>;
 
                     unsafe fn from_raw(raw_pointer: core::ptr::NonNull<lvgl_sys::lv_obj_t>) -> Option<Self>;
+                }
 
+                /// The `lv_obj_*` functions, available on every object.
+                pub trait CommonWidgetExt: NativeObject {
                     #(#methods)*
                 }
+
+                impl<T: NativeObject> CommonWidgetExt for T {}
             })
         } else {
+            let has_constructor = self.methods.iter().any(|m| m.is_constructor(self));
+            let default_impl = if self.default_constructor && has_constructor {
+                quote! {
+                    impl Default for #widget_name<'_> {
+                        fn default() -> Self {
+                            Self::new().expect("LVGL must be INITIALIZED")
+                        }
+                    }
+                }
+            } else {
+                quote!()
+            };
+
             Ok(quote! {
                 define_object!(#widget_name);
 
                 impl<'a> #widget_name<'a> {
                     #(#methods)*
                 }
+
+                #default_impl
             })
         }
     }
 }
 
+/// Changes to the wrapper generated for a single C function, see
+/// `CodeGen::with_overrides()`.
+#[derive(Debug, Clone, Default)]
+pub struct FuncOverride {
+    /// Name of the method, instead of the one derived from the C function.
+    pub rename: Option<String>,
+    /// Generates no wrapper at all.
+    pub skip: bool,
+}
+
 #[derive(Clone)]
 pub struct LvFunc {
     name: String,
     args: Vec<LvArg>,
     ret: Option<LvType>,
+    attrs: Vec<syn::Attribute>,
+    override_: FuncOverride,
 }
 
 impl LvFunc {
     pub fn new(name: String, args: Vec<LvArg>, ret: Option<LvType>) -> Self {
-        Self { name, args, ret }
+        Self {
+            name,
+            args,
+            ret,
+            attrs: Vec::new(),
+            override_: FuncOverride::default(),
+        }
+    }
+
+    /// Returns the doc comment lines of the C function, with the Doxygen
+    /// markers rewritten as plain prose.
+    pub fn doc_lines(&self) -> Vec<String> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .filter_map(|attr| match &attr.meta {
+                Meta::NameValue(MetaNameValue {
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(doc), ..
+                        }),
+                    ..
+                }) => Some(doxygen_to_prose(doc.value().trim())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn is_constructor(&self, parent: &LvWidget) -> bool {
+        parent.name != "obj" && self.name == format!("{}{}_create", LIB_PREFIX, parent.name)
     }
 
     pub fn is_method(&self) -> bool {
@@ -102,22 +234,64 @@ impl LvFunc {
         }
         false
     }
+
+    /// Points arguments and return values typed as one of `enums` to the
+    /// generated Rust enum.
+    pub fn resolve_enums(&mut self, enums: &[LvEnum]) {
+        for arg in self.args.iter_mut() {
+            arg.typ.resolve_enum(enums);
+        }
+        if let Some(ret) = self.ret.as_mut() {
+            ret.resolve_enum(enums);
+        }
+    }
+
+    /// Points arguments and return values holding a pointer to one of
+    /// `descriptors` to the generated wrapper struct.
+    pub fn resolve_descriptors(&mut self, descriptors: &[LvDescriptor]) {
+        for arg in self.args.iter_mut() {
+            arg.typ.resolve_descriptor(descriptors);
+        }
+        if let Some(ret) = self.ret.as_mut() {
+            ret.resolve_descriptor(descriptors);
+        }
+    }
 }
 
 impl Rusty for LvFunc {
     type Parent = LvWidget;
 
     fn code(&self, parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        if self.override_.skip {
+            return Err(WrapperError::Skip(SkipReason::Hidden));
+        }
         let templ = format!("{}{}_", LIB_PREFIX, parent.name.as_str());
-        let new_name = self.name.replace(templ.as_str(), "");
-        let func_name = format_ident!("{}", new_name);
+        let new_name = match &self.override_.rename {
+            Some(name) => name.clone(),
+            None => self.name.replace(templ.as_str(), ""),
+        };
+        let func_name = to_ident(&new_name);
         let original_func_name = format_ident!("{}", self.name.as_str());
+        let docs = self.doc_lines();
 
         // generate constructor
-        if new_name.as_str().eq("create") && parent.name != "obj" {
+        if self.is_constructor(parent) {
+            let new = if parent.default_constructor {
+                quote! {
+                    pub fn new() -> crate::LvResult<Self> {
+                        let mut parent = crate::display::get_scr_act(None)?;
+                        Self::create(&mut parent)
+                    }
+                }
+            } else {
+                quote!()
+            };
             return Ok(quote! {
 
                 pub fn create(parent: &mut impl crate::NativeObject) -> crate::LvResult<Self> {
+                    if !crate::is_init() {
+                        return Err(crate::LvError::Uninitialized);
+                    }
                     unsafe {
                         let ptr = lvgl_sys::#original_func_name(
                             parent.raw().as_mut(),
@@ -126,16 +300,12 @@ impl Rusty for LvFunc {
                             let core = <crate::Obj as Widget>::from_raw(raw).unwrap();
                             Ok(Self { core })
                         } else {
-                            Err(crate::LvError::InvalidReference)
+                            Err(crate::LvError::LvOOMemory)
                         }
                     }
                 }
 
-                pub fn new() -> crate::LvResult<Self> {
-                    let mut parent = crate::display::get_scr_act()?;
-                    Self::create(&mut parent)
-                }
-
+                #new
             });
         }
 
@@ -146,51 +316,55 @@ impl Rusty for LvFunc {
             // function returns something
             _ => {
                 let return_value: &LvType = self.ret.as_ref().unwrap();
-                if !return_value.is_pointer() {
-                    parse_str(&return_value.literal_name).expect(&format!(
-                        "Cannot parse {} as type",
-                        return_value.literal_name
-                    ))
+                if let Some(enum_name) = &return_value.enum_name {
+                    // Values unknown to the generated enum map to None
+                    let enum_name = format_ident!("{}", enum_name);
+                    quote!(Option<#enum_name>)
+                } else if !return_value.is_pointer() {
+                    let ty: TypePath = parse_str(return_value.rust_name()).map_err(|_| {
+                        WrapperError::Skip(SkipReason::UnparseableType(
+                            return_value.literal_name.clone(),
+                        ))
+                    })?;
+                    quote!(#ty)
+                } else if return_value.is_native_object() {
+                    // Pointers to objects are wrapped, NULL maps to None
+                    quote!(Option<crate::Obj>)
+                } else if let Some(descriptor_name) = &return_value.descriptor_name {
+                    let descriptor_name = format_ident!("{}", descriptor_name);
+                    quote!(Option<#descriptor_name>)
+                } else if return_value.is_const_str() || return_value.is_mut_str() {
+                    // Returned strings are borrowed from the object, NULL maps to None
+                    quote!(Option<&cstr_core::CStr>)
                 } else {
-                    println!("Return value is pointer ({})", return_value.literal_name);
-                    return Err(WrapperError::Skip);
+                    return Err(WrapperError::Skip(SkipReason::PointerReturn(
+                        return_value.literal_name.clone(),
+                    )));
                 }
             }
         };
 
-        // Make sure all arguments can be generated, skip the first arg (self)!
-        for arg in self.args.iter().skip(1) {
-            arg.code(self)?;
-        }
-
         // Generate the arguments being passed into the Rust 'wrapper'
         //
         // - Iif the first argument (of the C function) is const then we require a &self immutable reference, otherwise an &mut self reference
-        // - The arguments will be appended to the accumulator (args_accumulator) as they are generated in the closure
-        let args_decl =
-            self.args
-                .iter()
-                .enumerate()
-                .fold(quote!(), |args_accumulator, (arg_idx, arg)| {
-                    let next_arg = if arg_idx == 0 {
-                        if arg.get_type().is_const() {
-                            quote!(&self)
-                        } else {
-                            quote!(&mut self)
-                        }
+        // - If any argument can't be generated the whole function is skipped
+        let args_decl = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(arg_idx, arg)| {
+                if arg_idx == 0 {
+                    if arg.get_type().is_const() {
+                        Ok(quote!(&self))
                     } else {
-                        arg.code(self).unwrap()
-                    };
-
-                    // If the accummulator is empty then we call quote! only with the next_arg content
-                    if args_accumulator.is_empty() {
-                        quote! {#next_arg}
-                    }
-                    // Otherwise we append next_arg at the end of the accumulator
-                    else {
-                        quote! {#args_accumulator, #next_arg}
+                        Ok(quote!(&mut self))
                     }
-                });
+                } else {
+                    arg.code(self)
+                }
+            })
+            .collect::<WrapperResult<Vec<TokenStream>>>()?;
+        let args_decl = quote!(#(#args_decl),*);
 
         let args_preprocessing = self
             .args
@@ -242,8 +416,8 @@ impl Rusty for LvFunc {
 
         // Generate the arguments being passed into the FFI interface
         //
-        // - The first argument will be always self.core.raw().as_mut() (see quote! when arg_idx == 0), it's most likely a pointer to lv_obj_t
-        //   TODO: When handling getters this should be self.raw().as_ptr() instead, this also requires updating args_decl
+        // - The first argument will be always self.core.raw() (see quote! when arg_idx == 0), it's most likely a pointer to lv_obj_t
+        //   If it is const then the pointer is obtained with as_ptr(), matching the &self declared in args_decl
         // - The arguments will be appended to the accumulator (args_accumulator) as they are generated in the closure
         let ffi_args =
             self.args
@@ -251,10 +425,15 @@ impl Rusty for LvFunc {
                 .enumerate()
                 .fold(quote!(), |args_accumulator, (arg_idx, arg)| {
                     let next_arg = if arg_idx == 0 {
-                        if parent.name == "obj" {
-                            quote!(self.raw().as_mut())
+                        let raw = if parent.name == "obj" {
+                            quote!(self.raw())
+                        } else {
+                            quote!(self.core.raw())
+                        };
+                        if arg.get_type().is_const() {
+                            quote!(#raw.as_ptr())
                         } else {
-                            quote!(self.core.raw().as_mut())
+                            quote!(#raw.as_mut())
                         }
                     } else if arg.typ.is_mut_native_object() {
                         let var = arg.get_value_usage();
@@ -290,25 +469,95 @@ impl Rusty for LvFunc {
             None => quote!(;),
             _ => quote!(),
         };
+
+        // Getters are pointless to call without using their result
+        let is_getter = ["get_", "is_", "has_"]
+            .iter()
+            .any(|prefix| new_name.starts_with(prefix));
+        let must_use = if is_getter && self.ret.is_some() {
+            quote!(#[must_use])
+        } else {
+            quote!()
+        };
+
+        // Returned object pointers are checked for NULL and wrapped into an `Obj`
+        let ffi_call = match &self.ret {
+            Some(LvType {
+                enum_name: Some(enum_name),
+                ..
+            }) => {
+                let enum_name = format_ident!("{}", enum_name);
+                quote! {
+                    let val = lvgl_sys::#original_func_name(#ffi_args);
+                    #args_postprocessing
+                    #enum_name::try_from(val).ok()
+                }
+            }
+            Some(ret) if ret.is_native_object() => {
+                let ptr_cast = if ret.is_const_native_object() {
+                    quote!(ptr as *mut lvgl_sys::lv_obj_t)
+                } else {
+                    quote!(ptr)
+                };
+                quote! {
+                    let ptr = lvgl_sys::#original_func_name(#ffi_args);
+                    #args_postprocessing
+                    core::ptr::NonNull::new(#ptr_cast).and_then(|raw| <crate::Obj as Widget>::from_raw(raw))
+                }
+            }
+            Some(LvType {
+                descriptor_name: Some(descriptor_name),
+                literal_name,
+                ..
+            }) => {
+                let descriptor_name = format_ident!("{}", descriptor_name);
+                let ptr_cast = if literal_name.starts_with("* const ") {
+                    quote!(ptr as *mut _)
+                } else {
+                    quote!(ptr)
+                };
+                quote! {
+                    let ptr = lvgl_sys::#original_func_name(#ffi_args);
+                    #args_postprocessing
+                    core::ptr::NonNull::new(#ptr_cast).map(|raw| #descriptor_name { raw })
+                }
+            }
+            Some(ret) if ret.is_const_str() || ret.is_mut_str() => quote! {
+                let ptr = lvgl_sys::#original_func_name(#ffi_args);
+                #args_postprocessing
+                if ptr.is_null() {
+                    None
+                } else {
+                    Some(cstr_core::CStr::from_ptr(ptr))
+                }
+            },
+            _ => quote! {
+                lvgl_sys::#original_func_name(#ffi_args)#optional_semicolon
+                #args_postprocessing
+            },
+        };
+
         if parent.name == "obj" {
             // pub keyword cannot be used in traits
             Ok(quote! {
+                #(#[doc = #docs])*
+                #must_use
                 fn #func_name(#args_decl) -> #return_type {
                     unsafe {
                         #args_preprocessing
-                        lvgl_sys::#original_func_name(#ffi_args)#optional_semicolon
-                        #args_postprocessing
+                        #ffi_call
                         #explicit_ok
                     }
                 }
             })
         } else {
             Ok(quote! {
+                #(#[doc = #docs])*
+                #must_use
                 pub fn #func_name(#args_decl) -> #return_type {
                     unsafe {
                         #args_preprocessing
-                        lvgl_sys::#original_func_name(#ffi_args)#optional_semicolon
-                        #args_postprocessing
+                        #ffi_call
                         #explicit_ok
                     }
                 }
@@ -323,7 +572,7 @@ impl From<ForeignItemFn> for LvFunc {
             ReturnType::Default => None,
             ReturnType::Type(_, typ) => Some(typ.into()),
         };
-        Self::new(
+        let mut func = Self::new(
             ffi.sig.ident.to_string(),
             ffi.sig
                 .inputs
@@ -339,7 +588,28 @@ impl From<ForeignItemFn> for LvFunc {
                 .map(|a| a.clone().into())
                 .collect::<Vec<LvArg>>(),
             ret,
-        )
+        );
+        func.attrs = ffi.attrs;
+        func
+    }
+}
+
+/// Rewrites a single line of a Doxygen comment as plain prose. Parameters
+/// become list items and return values a "Returns" sentence.
+fn doxygen_to_prose(line: &str) -> String {
+    if let Some(param) = line.strip_prefix("@param ") {
+        match param.trim().split_once(' ') {
+            Some((name, desc)) => format!(" - `{}`: {}", name, desc.trim()),
+            None => format!(" - `{}`", param.trim()),
+        }
+    } else if let Some(ret) = line.strip_prefix("@return ") {
+        format!(" Returns {}", ret.trim())
+    } else if let Some(note) = line.strip_prefix("@note ") {
+        format!(" Note: {}", note.trim())
+    } else if line.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", line)
     }
 }
 
@@ -361,9 +631,13 @@ impl LvArg {
     }
 
     pub fn get_name_ident(&self) -> Ident {
-        // Filter Rust language keywords
-        syn::parse_str::<syn::Ident>(self.name.as_str())
-            .unwrap_or_else(|_| format_ident!("r#{}", self.name.as_str()))
+        to_ident(&self.name)
+    }
+
+    /// The temporary holding the raw pointer of a string argument while the
+    /// C function runs, named after the argument so each gets its own.
+    fn get_raw_ident(&self) -> Ident {
+        format_ident!("{}_raw", self.name)
     }
 
     pub fn get_preprocessing(&self) -> TokenStream {
@@ -372,8 +646,8 @@ impl LvArg {
 
         if self.get_type().is_mut_str() {
             // Convert CString to *mut i8
-            let name = format_ident!("{}", &self.name);
-            let name_raw = format_ident!("{}_raw", &self.name);
+            let name = self.get_name_ident();
+            let name_raw = self.get_raw_ident();
             quote! {
                 let #name_raw = #name.clone().into_raw();
             }
@@ -385,8 +659,8 @@ impl LvArg {
     pub fn get_postprocessing(&self) -> TokenStream {
         if self.get_type().is_mut_str() {
             // Convert *mut i8 back to CString
-            let name = format_ident!("{}", &self.name);
-            let name_raw = format_ident!("{}_raw", &self.name);
+            let name = self.get_name_ident();
+            let name_raw = self.get_raw_ident();
             quote! {
                 *#name = cstr_core::CString::from_raw(#name_raw);
             }
@@ -397,12 +671,28 @@ impl LvArg {
 
     pub fn get_value_usage(&self) -> TokenStream {
         let ident = self.get_name_ident();
-        if self.typ.is_const_str() {
+        if self.typ.is_enum() || self.typ.is_anim_enable() || self.typ.is_part() {
+            quote! {
+                #ident.into()
+            }
+        } else if self.typ.is_selector() {
+            quote! {
+                #ident.into().into()
+            }
+        } else if self.typ.is_descriptor() {
+            quote! {
+                #ident.raw.as_ptr()
+            }
+        } else if self.typ.is_color() {
+            quote! {
+                #ident.raw
+            }
+        } else if self.typ.is_const_str() {
             quote! {
                 #ident.as_ptr()
             }
         } else if self.typ.is_mut_str() {
-            let ident_raw = format_ident!("{}_raw", &ident);
+            let ident_raw = self.get_raw_ident();
             quote! {
                 #ident_raw
             }
@@ -434,6 +724,8 @@ impl Rusty for LvArg {
 pub struct LvType {
     literal_name: String,
     _r_type: Option<Box<syn::Type>>,
+    enum_name: Option<String>,
+    descriptor_name: Option<String>,
 }
 
 impl LvType {
@@ -441,6 +733,8 @@ impl LvType {
         Self {
             literal_name,
             _r_type: None,
+            enum_name: None,
+            descriptor_name: None,
         }
     }
 
@@ -448,11 +742,69 @@ impl LvType {
         Self {
             literal_name: r_type.to_token_stream().to_string(),
             _r_type: Some(r_type),
+            enum_name: None,
+            descriptor_name: None,
+        }
+    }
+
+    pub fn is_enum(&self) -> bool {
+        self.enum_name.is_some()
+    }
+
+    fn resolve_enum(&mut self, enums: &[LvEnum]) {
+        if let Some(e) = enums.iter().find(|e| e.name == self.literal_name) {
+            self.enum_name = Some(e.pascal_name());
         }
     }
 
+    pub fn is_descriptor(&self) -> bool {
+        self.descriptor_name.is_some()
+    }
+
+    fn resolve_descriptor(&mut self, descriptors: &[LvDescriptor]) {
+        if !self.is_pointer() {
+            return;
+        }
+        self.descriptor_name = descriptors
+            .iter()
+            .find(|d| d.name == self.pointee())
+            .map(|d| d.pascal_name());
+    }
+
+    /// The type a pointer points to, without the `* mut`/`* const` prefix.
+    pub fn pointee(&self) -> &str {
+        self.literal_name
+            .strip_prefix("* mut ")
+            .or_else(|| self.literal_name.strip_prefix("* const "))
+            .unwrap_or(&self.literal_name)
+    }
+
     pub fn is_const(&self) -> bool {
-        self.literal_name.starts_with("const ")
+        self.literal_name.starts_with("const ") || self.literal_name.starts_with("* const ")
+    }
+
+    /// The Rust primitive for C scalar types listed in `TYPE_MAPPINGS`,
+    /// otherwise the literal name.
+    pub fn rust_name(&self) -> &str {
+        TYPE_MAPPINGS
+            .get(self.literal_name.as_str())
+            .unwrap_or(&self.literal_name.as_str())
+    }
+
+    pub fn is_color(&self) -> bool {
+        self.literal_name == "lv_color_t"
+    }
+
+    pub fn is_anim_enable(&self) -> bool {
+        self.literal_name == "lv_anim_enable_t"
+    }
+
+    pub fn is_part(&self) -> bool {
+        self.literal_name == "lv_part_t"
+    }
+
+    pub fn is_selector(&self) -> bool {
+        self.literal_name == "lv_style_selector_t"
     }
 
     pub fn is_const_str(&self) -> bool {
@@ -473,6 +825,10 @@ impl LvType {
         self.literal_name == "* mut _lv_obj_t"
     }
 
+    pub fn is_native_object(&self) -> bool {
+        self.is_const_native_object() || self.is_mut_native_object()
+    }
+
     pub fn is_pointer(&self) -> bool {
         self.literal_name.starts_with('*')
     }
@@ -480,13 +836,33 @@ impl LvType {
     pub fn is_array(&self) -> bool {
         self.literal_name.starts_with("* mut *")
     }
+
+    /// Function pointers, either inline or through an LVGL `lv_*_cb_t` typedef.
+    pub fn is_callback(&self) -> bool {
+        self.literal_name.contains("extern \"C\" fn")
+            || (self.literal_name.starts_with(LIB_PREFIX) && self.literal_name.ends_with("_cb_t"))
+    }
 }
 
 impl Rusty for LvType {
     type Parent = LvArg;
 
     fn code(&self, _parent: &Self::Parent) -> WrapperResult<TokenStream> {
-        let val = if self.is_const_str() {
+        let val = if let Some(enum_name) = &self.enum_name {
+            let enum_name = format_ident!("{}", enum_name);
+            quote!(#enum_name)
+        } else if let Some(descriptor_name) = &self.descriptor_name {
+            let descriptor_name = format_ident!("{}", descriptor_name);
+            quote!(#descriptor_name)
+        } else if self.is_color() {
+            quote!(crate::Color)
+        } else if self.is_anim_enable() {
+            quote!(crate::AnimEnable)
+        } else if self.is_part() {
+            quote!(crate::Part)
+        } else if self.is_selector() {
+            quote!(impl Into<crate::Selector>)
+        } else if self.is_const_str() {
             quote!(&cstr_core::CStr)
         } else if self.is_mut_str() {
             quote!(&mut cstr_core::CString)
@@ -495,17 +871,31 @@ impl Rusty for LvType {
         } else if self.is_mut_native_object() {
             quote!(&mut impl NativeObject)
         } else if self.is_array() {
-            println!("Array as argument ({})", self.literal_name);
-            return Err(WrapperError::Skip);
+            // LVGL keeps arrays like the button matrix map and scans them for
+            // a terminator, which a borrowed slice can't guarantee
+            return Err(WrapperError::Skip(SkipReason::ArrayArgument(
+                self.literal_name.clone(),
+            )));
+        } else if self.is_callback() {
+            // The closure would need to be kept alive and passed through user
+            // data, which differs per callback; see `on_event` for events.
+            return Err(WrapperError::Skip(SkipReason::CallbackArgument(
+                self.literal_name.clone(),
+            )));
         } else {
             let literal_name = self.literal_name.as_str();
             let raw_name = literal_name.replace("* const ", "").replace("* mut ", "");
             if raw_name == "cty :: c_void" {
-                println!("Void pointer as argument ({literal_name})");
-                return Err(WrapperError::Skip);
+                return Err(WrapperError::Skip(SkipReason::VoidPointerArgument(
+                    self.literal_name.clone(),
+                )));
             }
-            let ty: TypePath =
-                parse_str(&raw_name).expect(&format!("Cannot parse {raw_name} to a type"));
+            let raw_name = TYPE_MAPPINGS
+                .get(raw_name.as_str())
+                .map_or(raw_name.as_str(), |name| name);
+            let ty: TypePath = parse_str(raw_name).map_err(|_| {
+                WrapperError::Skip(SkipReason::UnparseableType(self.literal_name.clone()))
+            })?;
             if self.literal_name.starts_with("* mut") {
                 quote!(&mut #ty)
             } else if self.literal_name.starts_with("*") {
@@ -525,35 +915,349 @@ impl From<Box<syn::Type>> for LvType {
     }
 }
 
+/// An integer typedef from LVGL whose values are defined by a C enum.
+#[derive(Clone)]
+pub struct LvEnum {
+    name: String,
+    /// Pairs of the C constant and the name of the Rust variant.
+    variants: Vec<(String, String)>,
+    /// Anonymous C enums have constants of a different type than the typedef.
+    needs_cast: bool,
+}
+
+impl LvEnum {
+    fn pascal_name(&self) -> String {
+        let name = self.name.strip_prefix(LIB_PREFIX).unwrap_or(&self.name);
+        to_pascal_case(name.strip_suffix("_t").unwrap_or(name))
+    }
+
+    fn new(name: String, constants: Vec<String>, needs_cast: bool) -> Self {
+        // Strip the words shared by all constants, e.g. `LV_CHART_TYPE_`
+        let words: Vec<Vec<&str>> = constants.iter().map(|c| c.split('_').collect()).collect();
+        let max_prefix = words.iter().map(|w| w.len() - 1).min().unwrap_or(0);
+        let prefix_len = (0..max_prefix)
+            .take_while(|&i| words.iter().all(|w| w[i] == words[0][i]))
+            .count();
+
+        let mut variants: Vec<(String, String)> = Vec::new();
+        for (constant, words) in constants.iter().zip(words.iter()) {
+            let mut variant = to_pascal_case(&words[prefix_len..].join("_"));
+            if variant.starts_with(|c: char| c.is_ascii_digit()) {
+                // Identifiers can't start with a digit, keep the last word of the prefix
+                variant = format!("{}{}", to_pascal_case(words[prefix_len - 1]), variant);
+            }
+            if !variants.iter().any(|(_, v)| *v == variant) {
+                variants.push((constant.clone(), variant));
+            }
+        }
+
+        Self {
+            name,
+            variants,
+            needs_cast,
+        }
+    }
+}
+
+impl Rusty for LvEnum {
+    type Parent = ();
+
+    fn code(&self, _parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        let enum_name = format_ident!("{}", self.pascal_name());
+        let raw_name = format_ident!("{}", self.name);
+        let variants: Vec<Ident> = self
+            .variants
+            .iter()
+            .map(|(_, v)| format_ident!("{}", v))
+            .collect();
+        let values: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .map(|(c, _)| {
+                let constant = format_ident!("{}", c);
+                if self.needs_cast {
+                    quote!(lvgl_sys::#constant as #raw_name)
+                } else {
+                    quote!(lvgl_sys::#constant)
+                }
+            })
+            .collect();
+
+        Ok(quote! {
+            #[repr(C)]
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub enum #enum_name {
+                #(#variants,)*
+            }
+
+            impl From<#enum_name> for #raw_name {
+                fn from(value: #enum_name) -> Self {
+                    match value {
+                        #(#enum_name::#variants => #values,)*
+                    }
+                }
+            }
+
+            impl TryFrom<#raw_name> for #enum_name {
+                type Error = ();
+
+                fn try_from(value: #raw_name) -> Result<Self, Self::Error> {
+                    #(if value == #values {
+                        return Ok(#enum_name::#variants);
+                    })*
+                    Err(())
+                }
+            }
+        })
+    }
+}
+
+/// A struct belonging to a widget which LVGL hands out by pointer, like a
+/// chart series or a meter scale.
+#[derive(Clone)]
+pub struct LvDescriptor {
+    name: String,
+}
+
+impl LvDescriptor {
+    fn pascal_name(&self) -> String {
+        let name = self.name.strip_prefix(LIB_PREFIX).unwrap_or(&self.name);
+        to_pascal_case(name.strip_suffix("_t").unwrap_or(name))
+    }
+}
+
+impl Rusty for LvDescriptor {
+    type Parent = ();
+
+    fn code(&self, _parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        let descriptor_name = format_ident!("{}", self.pascal_name());
+        let raw_name = format_ident!("{}", self.name);
+
+        Ok(quote! {
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub struct #descriptor_name {
+                raw: core::ptr::NonNull<#raw_name>,
+            }
+
+            impl #descriptor_name {
+                pub fn raw(&self) -> core::ptr::NonNull<#raw_name> {
+                    self.raw
+                }
+            }
+        })
+    }
+}
+
+/// How widget constructors are told apart from other functions, see
+/// `CodeGen::from_with()`.
+#[derive(Debug, Clone)]
+pub struct WidgetConstructors {
+    /// Regex matching the name of a constructor, whose first capture group
+    /// is the widget name.
+    pub pattern: String,
+    /// Number of arguments of a constructor, including the parent.
+    pub args: usize,
+}
+
+impl Default for WidgetConstructors {
+    fn default() -> Self {
+        Self {
+            pattern: format!("^{}([a-z0-9_]+)_create$", LIB_PREFIX),
+            args: 1,
+        }
+    }
+}
+
 pub struct CodeGen {
     functions: Vec<LvFunc>,
     widgets: Vec<LvWidget>,
+    enums: Vec<LvEnum>,
+    descriptors: Vec<LvDescriptor>,
+    overrides: HashMap<String, FuncOverride>,
 }
 
 impl CodeGen {
     pub fn from(code: &str) -> CGResult<Self> {
-        let functions = Self::load_func_defs(code)?;
-        let widgets = Self::extract_widgets(&functions)?;
-        Ok(Self { functions, widgets })
+        Self::from_with(code, &WidgetConstructors::default())
+    }
+
+    /// Like `from()`, but finds the widgets through the given constructors.
+    /// A `create()` taking only the parent is still generated from
+    /// `lv_<widget>_create()`, other constructors are left as methods.
+    pub fn from_with(code: &str, constructors: &WidgetConstructors) -> CGResult<Self> {
+        let enums = Self::load_enum_defs(code)?;
+        let mut functions = Self::load_func_defs(code)?;
+        let widget_names = Self::get_widget_names(&functions, constructors)?;
+        let descriptors = Self::extract_descriptors(&functions, &widget_names);
+        for f in functions.iter_mut() {
+            f.resolve_enums(&enums);
+            f.resolve_descriptors(&descriptors);
+        }
+        let widgets = Self::extract_widgets(&functions, &widget_names)?;
+        Ok(Self {
+            functions,
+            widgets,
+            enums,
+            descriptors,
+            overrides: HashMap::new(),
+        })
+    }
+
+    /// Stops generating `new()` and `Default` for the given widgets, which
+    /// can't be created directly on the active screen.
+    pub fn without_default_constructors(mut self, widget_names: &[&str]) -> Self {
+        for widget in self.widgets.iter_mut() {
+            if widget_names.contains(&widget.name.as_str()) {
+                widget.default_constructor = false;
+            }
+        }
+        self
+    }
+
+    /// Stops generating wrapper structs for the given descriptor types, which
+    /// are values rather than handles or are wrapped by hand in the `lvgl`
+    /// crate. Functions using them take and return raw pointers again.
+    pub fn without_descriptors(mut self, descriptor_names: &[&str]) -> Self {
+        self.descriptors
+            .retain(|d| !descriptor_names.contains(&d.name.as_str()));
+        for widget in self.widgets.iter_mut() {
+            for func in widget.methods.iter_mut() {
+                func.resolve_descriptors(&self.descriptors);
+            }
+        }
+        self
+    }
+
+    /// Renames or hides the wrappers of the given C functions, e.g. to avoid
+    /// a collision with a method written by hand. Hidden functions are still
+    /// listed by `skipped()`.
+    pub fn with_overrides(mut self, overrides: &[(&str, FuncOverride)]) -> Self {
+        self.overrides.extend(
+            overrides
+                .iter()
+                .map(|(name, func_override)| (name.to_string(), func_override.clone())),
+        );
+        for widget in self.widgets.iter_mut() {
+            for func in widget.methods.iter_mut() {
+                if let Some(func_override) = self.overrides.get(&func.name) {
+                    func.override_ = func_override.clone();
+                }
+            }
+        }
+        self
+    }
+
+    /// Lists the functions of every widget no wrapper is generated for, by
+    /// name, along with the reason. Useful to audit what the bindings cover.
+    pub fn skipped(&self) -> Vec<(String, SkipReason)> {
+        let mut skipped: Vec<(String, SkipReason)> = self
+            .widgets
+            .iter()
+            .flat_map(|widget| {
+                widget
+                    .methods
+                    .iter()
+                    .filter_map(move |func| match func.code(widget) {
+                        Err(WrapperError::Skip(reason)) => Some((func.name.clone(), reason)),
+                        Ok(_) => None,
+                    })
+            })
+            .collect();
+        skipped.sort_by(|a, b| a.0.cmp(&b.0));
+        skipped
+    }
+
+    pub fn get_descriptors(&self) -> &Vec<LvDescriptor> {
+        &self.descriptors
+    }
+
+    /// Finds the `lv_<widget>_*_t` structs returned by pointer from the
+    /// functions of that widget.
+    fn extract_descriptors(functions: &[LvFunc], widget_names: &[String]) -> Vec<LvDescriptor> {
+        let mut names: Vec<String> = functions
+            .iter()
+            .filter_map(|f| {
+                let ret = f.ret.as_ref().filter(|ret| ret.is_pointer())?;
+                let pointee = ret.pointee();
+                widget_names
+                    .iter()
+                    .filter(|w| w.as_str() != "obj")
+                    .map(|w| format!("{}{}_", LIB_PREFIX, w))
+                    .find(|prefix| f.name.starts_with(prefix) && pointee.starts_with(prefix))
+                    .filter(|_| pointee.ends_with("_t"))
+                    .map(|_| pointee.to_string())
+            })
+            .collect();
+        names.sort();
+        names.dedup();
+
+        names
+            .into_iter()
+            .map(|name| LvDescriptor { name })
+            .collect()
     }
 
     pub fn get_widgets(&self) -> &Vec<LvWidget> {
         &self.widgets
     }
 
-    fn extract_widgets(functions: &[LvFunc]) -> CGResult<Vec<LvWidget>> {
-        let widget_names = Self::get_widget_names(functions);
+    /// Generates the enums, descriptors and widgets, followed by a
+    /// `WidgetKind` enum listing all widgets and a `prelude` module
+    /// re-exporting them.
+    pub fn code(&self) -> TokenStream {
+        let enums_impl: Vec<TokenStream> = self.enums.iter().flat_map(|e| e.code(&())).collect();
+        let descriptors_impl: Vec<TokenStream> = self
+            .descriptors
+            .iter()
+            .flat_map(|d| d.code(&()))
+            .collect();
+
+        let mut widgets: Vec<&LvWidget> = self.widgets.iter().collect();
+        widgets.sort_by(|a, b| a.name.cmp(&b.name));
+        let widgets_impl: Vec<TokenStream> = widgets.iter().flat_map(|w| w.code(&())).collect();
+        let widget_names: Vec<Ident> = widgets
+            .iter()
+            .filter(|w| w.name != "obj")
+            .map(|w| format_ident!("{}", w.pascal_name()))
+            .collect();
+
+        quote! {
+            #(#enums_impl)*
+            #(#descriptors_impl)*
+            #(#widgets_impl)*
+
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub enum WidgetKind {
+                #(#widget_names,)*
+            }
+
+            pub mod prelude {
+                pub use super::{CommonWidgetExt, Widget, WidgetKind, #(#widget_names),*};
+            }
+        }
+    }
+
+    pub fn get_enums(&self) -> &Vec<LvEnum> {
+        &self.enums
+    }
 
+    fn extract_widgets(functions: &[LvFunc], widget_names: &[String]) -> CGResult<Vec<LvWidget>> {
         let widgets = functions.iter().fold(HashMap::new(), |mut ws, f| {
-            for widget_name in &widget_names {
-                if f.name
-                    .starts_with(format!("{}{}", LIB_PREFIX, widget_name).as_str())
-                    && f.is_method()
-                {
+            // The longest matching name wins, so `lv_btnmatrix_*` doesn't end up in `btn`
+            let widget_name = widget_names
+                .iter()
+                .filter(|widget_name| {
+                    f.name
+                        .starts_with(format!("{}{}_", LIB_PREFIX, widget_name).as_str())
+                })
+                .max_by_key(|widget_name| widget_name.len());
+            if let Some(widget_name) = widget_name {
+                if f.is_method() {
                     ws.entry(widget_name.clone())
                         .or_insert_with(|| LvWidget {
                             name: widget_name.clone(),
                             methods: Vec::new(),
+                            default_constructor: true,
                         })
                         .methods
                         .push(f.clone())
@@ -565,24 +1269,29 @@ impl CodeGen {
         Ok(widgets.values().cloned().collect())
     }
 
-    fn get_widget_names(functions: &[LvFunc]) -> Vec<String> {
-        let reg = format!("^{}([^_]+)_create$", LIB_PREFIX);
-        let create_func = Regex::new(reg.as_str()).unwrap();
+    fn get_widget_names(
+        functions: &[LvFunc],
+        constructors: &WidgetConstructors,
+    ) -> CGResult<Vec<String>> {
+        let create_func = Regex::new(constructors.pattern.as_str())?;
 
-        functions
+        let candidates: Vec<String> = functions
             .iter()
-            .filter(|e| create_func.is_match(e.name.as_str()) && e.args.len() == 1)
-            .map(|f| {
-                String::from(
-                    create_func
-                        .captures(f.name.as_str())
-                        .unwrap()
-                        .get(1)
-                        .unwrap()
-                        .as_str(),
-                )
+            .filter(|e| e.args.len() == constructors.args)
+            .filter_map(|f| Some(create_func.captures(f.name.as_str())?.get(1)?.as_str()))
+            .map(String::from)
+            .collect();
+        // Parts of a widget, like `lv_menu_cont_create()`, are named after
+        // its method prefix rather than being widgets of their own
+        Ok(candidates
+            .iter()
+            .filter(|name| {
+                !candidates
+                    .iter()
+                    .any(|other| name.starts_with(format!("{}_", other).as_str()))
             })
-            .collect::<Vec<_>>()
+            .cloned()
+            .collect())
     }
 
     pub fn load_func_defs(bindgen_code: &str) -> CGResult<Vec<LvFunc>> {
@@ -612,14 +1321,70 @@ impl CodeGen {
         Ok(fns)
     }
 
-    pub fn get_function_names(&self) -> CGResult<Vec<String>> {
-        Ok(self.functions.iter().map(|f| f.name.clone()).collect())
-    }
-}
+    pub fn load_enum_defs(bindgen_code: &str) -> CGResult<Vec<LvEnum>> {
+        let ast: syn::File = syn::parse_str(bindgen_code)?;
+        let mut aliases = Vec::new();
+        let mut constants = Vec::new();
+        for item in ast.items {
+            match item {
+                Item::Type(t) => {
+                    aliases.push((t.ident.to_string(), t.ty.to_token_stream().to_string()))
+                }
+                Item::Const(c) => {
+                    constants.push((c.ident.to_string(), c.ty.to_token_stream().to_string()))
+                }
+                _ => {}
+            }
+        }
 
-#[cfg(test)]
-mod test {
-    use crate::{CodeGen, LvArg, LvFunc, LvType, LvWidget, Rusty};
+        let enums = aliases
+            .iter()
+            .filter(|(name, ty)| {
+                name.starts_with(LIB_PREFIX)
+                    && name.ends_with("_t")
+                    && INT_TYPES.contains(&ty.as_str())
+                    && !NON_ENUM_TYPES.contains(&name.as_str())
+            })
+            .filter_map(|(name, _)| {
+                // `typedef enum {...} lv_x_t;` gives constants named `lv_x_t_LV_X_...`
+                let named_prefix = format!("{}_", name);
+                let named: Vec<String> = constants
+                    .iter()
+                    .filter(|(c, ty)| ty == name && c.starts_with(&named_prefix))
+                    .filter(|(c, _)| !c[named_prefix.len()..].starts_with('_'))
+                    .map(|(c, _)| c.clone())
+                    .collect();
+                if !named.is_empty() {
+                    return Some(LvEnum::new(name.clone(), named, false));
+                }
+
+                // `enum {...}; typedef uint8_t lv_x_t;` gives constants named `LV_X_...`
+                let anon_prefix = format!("{}_", name[..name.len() - 2].to_uppercase());
+                let anonymous: Vec<String> = constants
+                    .iter()
+                    .filter(|(c, ty)| ty.starts_with("_bindgen_ty_") && c.starts_with(&anon_prefix))
+                    .map(|(c, _)| c.clone())
+                    .collect();
+                if !anonymous.is_empty() {
+                    return Some(LvEnum::new(name.clone(), anonymous, true));
+                }
+                None
+            })
+            .collect();
+        Ok(enums)
+    }
+
+    pub fn get_function_names(&self) -> CGResult<Vec<String>> {
+        Ok(self.functions.iter().map(|f| f.name.clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CodeGen, FuncOverride, LvArg, LvFunc, LvType, LvWidget, Rusty, SkipReason,
+        WidgetConstructors, WrapperError,
+    };
     use quote::quote;
 
     #[test]
@@ -635,7 +1400,7 @@ mod test {
 
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let ffn = cg.get(0).unwrap();
+        let ffn = cg.first().unwrap();
         assert_eq!(ffn.name, "lv_obj_get_screen");
         assert_eq!(ffn.args[0].name, "obj");
     }
@@ -685,7 +1450,7 @@ mod test {
             ),
         ];
 
-        let widget_names = CodeGen::get_widget_names(&funcs);
+        let widget_names = CodeGen::get_widget_names(&funcs, &Default::default()).unwrap();
 
         assert_eq!(widget_names.len(), 3);
     }
@@ -704,6 +1469,7 @@ mod test {
         let arc_widget = LvWidget {
             name: "arc".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = arc_set_bg_end_angle.code(&arc_widget).unwrap();
@@ -730,15 +1496,19 @@ mod test {
         };
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let label_set_text = cg.get(0).unwrap().clone();
+        let label_set_text = cg.first().unwrap().clone();
         let parent_widget = LvWidget {
             name: "label".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = label_set_text.code(&parent_widget).unwrap();
         let expected_code = quote! {
 
+            #[doc = " Set a new text for a label. Memory will be allocated to store the text by the label."]
+            #[doc = " - `label`: pointer to a label object"]
+            #[doc = " - `text`: '\\0' terminated character string. NULL to refresh with the current text."]
             pub fn set_text(&mut self, text: &cstr_core::CStr) -> () {
                 unsafe {
                     lvgl_sys::lv_label_set_text(
@@ -762,20 +1532,21 @@ mod test {
         };
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let dropdown_get_selected_str = cg.get(0).unwrap().clone();
+        let dropdown_get_selected_str = cg.first().unwrap().clone();
         let parent_widget = LvWidget {
             name: "dropdown".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = dropdown_get_selected_str.code(&parent_widget).unwrap();
         let expected_code = quote! {
 
-            pub fn get_selected_str(&mut self, buf: &mut cstr_core::CString, buf_size:u32) -> () {
+            pub fn get_selected_str(&self, buf: &mut cstr_core::CString, buf_size:u32) -> () {
                 unsafe {
                     let buf_raw = buf.clone().into_raw();
                     lvgl_sys::lv_dropdown_get_selected_str(
-                        self.core.raw().as_mut(),
+                        self.core.raw().as_ptr(),
                         buf_raw,
                         buf_size
                     );
@@ -800,14 +1571,18 @@ mod test {
         };
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let label_set_text = cg.get(0).unwrap().clone();
+        let label_set_text = cg.first().unwrap().clone();
         let parent_widget = LvWidget {
             name: "label".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = label_set_text.code(&parent_widget).unwrap();
         let expected_code = quote! {
+            #[doc = " Set a new text for a label. Memory will be allocated to store the text by the label."]
+            #[doc = " - `label`: pointer to a label object"]
+            #[doc = " - `text`: '\\0' terminated character string. NULL to refresh with the current text."]
             pub fn set_text(&mut self, text: &cstr_core::CStr) -> () {
                 unsafe {
                     lvgl_sys::lv_label_set_text(
@@ -833,18 +1608,19 @@ mod test {
         };
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let arc_rotate_obj_to_angle = cg.get(0).unwrap().clone();
+        let arc_rotate_obj_to_angle = cg.first().unwrap().clone();
         let parent_widget = LvWidget {
             name: "arc".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = arc_rotate_obj_to_angle.code(&parent_widget).unwrap();
         let expected_code = quote! {
-            pub fn rotate_obj_to_angle(&mut self, obj_to_rotate: &mut impl NativeObject, r_offset: lv_coord_t) -> () {
+            pub fn rotate_obj_to_angle(&self, obj_to_rotate: &mut impl NativeObject, r_offset: lv_coord_t) -> () {
                 unsafe {
                     lvgl_sys::lv_arc_rotate_obj_to_angle(
-                        self.core.raw().as_mut(),
+                        self.core.raw().as_ptr(),
                         obj_to_rotate.raw().as_mut(),
                         r_offset
                     );
@@ -855,6 +1631,43 @@ mod test {
         assert_eq!(code.to_string(), expected_code.to_string());
     }
 
+    #[test]
+    fn generate_method_wrapper_with_doc_comments() {
+        let bindgen_code = quote! {
+            extern "C" {
+                #[doc = " Get the recoloring attribute"]
+                #[doc = " @param obj pointer to a label object"]
+                #[doc = " @return true: enable recoloring, false: disable"]
+                pub fn lv_label_get_recolor(obj: *mut lv_obj_t) -> bool;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let label_get_recolor = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "label".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = label_get_recolor.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            #[doc = " Get the recoloring attribute"]
+            #[doc = " - `obj`: pointer to a label object"]
+            #[doc = " Returns true: enable recoloring, false: disable"]
+            #[must_use]
+            pub fn get_recolor(&mut self) -> bool {
+                unsafe {
+                    lvgl_sys::lv_label_get_recolor(
+                        self.core.raw().as_mut()
+                    )
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
     #[test]
     fn generate_method_wrapper_for_boolean_return() {
         let bindgen_code = quote! {
@@ -864,14 +1677,16 @@ mod test {
         };
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let label_get_recolor = cg.get(0).unwrap().clone();
+        let label_get_recolor = cg.first().unwrap().clone();
         let parent_widget = LvWidget {
             name: "label".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = label_get_recolor.code(&parent_widget).unwrap();
         let expected_code = quote! {
+            #[must_use]
             pub fn get_recolor(&mut self) -> bool {
                 unsafe {
                     lvgl_sys::lv_label_get_recolor(
@@ -884,27 +1699,123 @@ mod test {
         assert_eq!(code.to_string(), expected_code.to_string());
     }
 
+    #[test]
+    fn generate_const_getter_for_boolean_return() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_label_get_recolor(obj: *const lv_obj_t) -> bool;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let label_get_recolor = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "label".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = label_get_recolor.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            #[must_use]
+            pub fn get_recolor(&self) -> bool {
+                unsafe {
+                    lvgl_sys::lv_label_get_recolor(
+                        self.core.raw().as_ptr()
+                    )
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_method_wrapper_for_obj_pointer_return() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_obj_get_parent(obj: *const lv_obj_t) -> *mut lv_obj_t;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let obj_get_parent = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "obj".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = obj_get_parent.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            #[must_use]
+            fn get_parent(&self) -> Option<crate::Obj> {
+                unsafe {
+                    let ptr = lvgl_sys::lv_obj_get_parent(
+                        self.raw().as_ptr()
+                    );
+                    core::ptr::NonNull::new(ptr).and_then(|raw| <crate::Obj as Widget>::from_raw(raw))
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
     #[test]
     fn generate_method_wrapper_for_uint32_return() {
         let bindgen_code = quote! {
             extern "C" {
-                pub fn lv_label_get_text_selection_start(label: *mut lv_obj_t) -> u32;
+                pub fn lv_label_get_text_selection_start(label: *const lv_obj_t) -> u32;
             }
         };
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let label_get_text_selection_start = cg.get(0).unwrap().clone();
+        let label_get_text_selection_start = cg.first().unwrap().clone();
         let parent_widget = LvWidget {
             name: "label".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = label_get_text_selection_start.code(&parent_widget).unwrap();
         let expected_code = quote! {
-            pub fn get_text_selection_start(&mut self) -> u32 {
+            #[must_use]
+            pub fn get_text_selection_start(&self) -> u32 {
                 unsafe {
                     lvgl_sys::lv_label_get_text_selection_start(
-                        self.core.raw().as_mut()
+                        self.core.raw().as_ptr()
+                    )
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_const_getter_with_immutable_self() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_arc_get_value(obj: *const lv_obj_t) -> i16;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let arc_get_value = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "arc".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = arc_get_value.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            #[must_use]
+            pub fn get_value(&self) -> i16 {
+                unsafe {
+                    lvgl_sys::lv_arc_get_value(
+                        self.core.raw().as_ptr()
                     )
                 }
             }
@@ -918,6 +1829,7 @@ mod test {
         let arc_widget = LvWidget {
             name: "arc".to_string(),
             methods: vec![],
+            default_constructor: true,
         };
 
         let code = arc_widget.code(&()).unwrap();
@@ -950,6 +1862,7 @@ mod test {
         let arc_widget = LvWidget {
             name: "arc".to_string(),
             methods: vec![arc_create],
+            default_constructor: true,
         };
 
         let code = arc_widget.code(&()).unwrap();
@@ -958,6 +1871,9 @@ mod test {
 
             impl<'a> Arc<'a> {
                 pub fn create(parent: &mut impl crate::NativeObject) -> crate::LvResult<Self> {
+                    if !crate::is_init() {
+                        return Err(crate::LvError::Uninitialized);
+                    }
                     unsafe {
                         let ptr = lvgl_sys::lv_arc_create(
                             parent.raw().as_mut(),
@@ -966,18 +1882,815 @@ mod test {
                             let core = <crate::Obj as Widget>::from_raw(raw).unwrap();
                             Ok(Self { core })
                         } else {
-                            Err(crate::LvError::InvalidReference)
+                            Err(crate::LvError::LvOOMemory)
                         }
                     }
                 }
 
                 pub fn new() -> crate::LvResult<Self> {
-                    let mut parent = crate::display::get_scr_act()?;
+                    let mut parent = crate::display::get_scr_act(None)?;
                     Self::create(&mut parent)
                 }
             }
+
+            impl Default for Arc<'_> {
+                fn default() -> Self {
+                    Self::new().expect("LVGL must be INITIALIZED")
+                }
+            }
         };
 
         assert_eq!(code.to_string(), expected_code.to_string());
     }
+
+    #[test]
+    fn generate_enum_and_setter_consuming_it() {
+        let bindgen_code = quote! {
+            pub const LV_CHART_TYPE_NONE: _bindgen_ty_30 = 0;
+            pub const LV_CHART_TYPE_LINE: _bindgen_ty_30 = 1;
+            pub const LV_CHART_TYPE_BAR: _bindgen_ty_30 = 2;
+            pub const LV_CHART_TYPE_SCATTER: _bindgen_ty_30 = 3;
+            pub type _bindgen_ty_30 = cty::c_uint;
+            pub type lv_chart_type_t = u8;
+            extern "C" {
+                pub fn lv_chart_set_type(obj: *mut lv_obj_t, type_: lv_chart_type_t);
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+
+        let chart_type = cg.get_enums().first().unwrap();
+        let code = chart_type.code(&()).unwrap();
+        let expected_code = quote! {
+            #[repr(C)]
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub enum ChartType {
+                None,
+                Line,
+                Bar,
+                Scatter,
+            }
+
+            impl From<ChartType> for lv_chart_type_t {
+                fn from(value: ChartType) -> Self {
+                    match value {
+                        ChartType::None => lvgl_sys::LV_CHART_TYPE_NONE as lv_chart_type_t,
+                        ChartType::Line => lvgl_sys::LV_CHART_TYPE_LINE as lv_chart_type_t,
+                        ChartType::Bar => lvgl_sys::LV_CHART_TYPE_BAR as lv_chart_type_t,
+                        ChartType::Scatter => lvgl_sys::LV_CHART_TYPE_SCATTER as lv_chart_type_t,
+                    }
+                }
+            }
+
+            impl TryFrom<lv_chart_type_t> for ChartType {
+                type Error = ();
+
+                fn try_from(value: lv_chart_type_t) -> Result<Self, Self::Error> {
+                    if value == lvgl_sys::LV_CHART_TYPE_NONE as lv_chart_type_t {
+                        return Ok(ChartType::None);
+                    }
+                    if value == lvgl_sys::LV_CHART_TYPE_LINE as lv_chart_type_t {
+                        return Ok(ChartType::Line);
+                    }
+                    if value == lvgl_sys::LV_CHART_TYPE_BAR as lv_chart_type_t {
+                        return Ok(ChartType::Bar);
+                    }
+                    if value == lvgl_sys::LV_CHART_TYPE_SCATTER as lv_chart_type_t {
+                        return Ok(ChartType::Scatter);
+                    }
+                    Err(())
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        let chart_set_type = cg.functions.first().unwrap();
+        let parent_widget = LvWidget {
+            name: "chart".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+        let code = chart_set_type.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            pub fn set_type(&mut self, type_: ChartType) -> () {
+                unsafe {
+                    lvgl_sys::lv_chart_set_type(self.core.raw().as_mut(), type_.into());
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_method_wrapper_for_color_argument() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_obj_set_style_bg_color(obj: *mut lv_obj_t, value: lv_color_t, selector: lv_style_selector_t);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let set_bg_color = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "obj".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = set_bg_color.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            fn set_style_bg_color(&mut self, value: crate::Color, selector: impl Into<crate::Selector>) -> () {
+                unsafe {
+                    lvgl_sys::lv_obj_set_style_bg_color(
+                        self.raw().as_mut(),
+                        value.raw,
+                        selector.into().into()
+                    );
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_method_wrapper_for_part_argument() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_obj_get_style_prop(obj: *const lv_obj_t, part: lv_part_t, prop: lv_style_prop_t) -> lv_style_value_t;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let get_style_prop = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "obj".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = get_style_prop.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            #[must_use]
+            fn get_style_prop(&self, part: crate::Part, prop: lv_style_prop_t) -> lv_style_value_t {
+                unsafe {
+                    lvgl_sys::lv_obj_get_style_prop(
+                        self.raw().as_ptr(),
+                        part.into(),
+                        prop
+                    )
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_method_wrapper_for_float_return() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_meter_get_scale_ratio(obj: *const lv_obj_t, scale: cty::c_double) -> cty::c_float;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let get_scale_ratio = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "meter".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = get_scale_ratio.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            #[must_use]
+            pub fn get_scale_ratio(&self, scale: f64) -> f32 {
+                unsafe {
+                    lvgl_sys::lv_meter_get_scale_ratio(
+                        self.core.raw().as_ptr(),
+                        scale
+                    )
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn skip_method_wrapper_for_callback_argument() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_obj_add_event_cb(
+                    obj: *mut lv_obj_t,
+                    event_cb: lv_event_cb_t,
+                    filter: lv_event_code_t,
+                    user_data: *mut cty::c_void,
+                ) -> *mut _lv_event_dsc_t;
+                pub fn lv_chart_set_draw_cb(
+                    obj: *mut lv_obj_t,
+                    cb: ::core::option::Option<unsafe extern "C" fn(obj: *mut lv_obj_t)>,
+                );
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let parent_widget = LvWidget {
+            name: "obj".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+        for func in cg {
+            assert!(matches!(
+                func.code(&parent_widget),
+                Err(WrapperError::Skip(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn skip_method_wrapper_for_str_array_argument() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_btnmatrix_set_map(obj: *mut lv_obj_t, map: *mut *const cty::c_char);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let btnmatrix_set_map = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "btnmatrix".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        assert!(matches!(
+            btnmatrix_set_map.code(&parent_widget),
+            Err(WrapperError::Skip(SkipReason::ArrayArgument(typ)))
+                if typ == "* mut * const cty :: c_char"
+        ));
+    }
+
+    #[test]
+    fn skip_method_wrapper_with_unsupported_argument() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_img_set_src(obj: *mut lv_obj_t, src: *const cty::c_void);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let img_set_src = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "img".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        assert!(matches!(
+            img_set_src.code(&parent_widget),
+            Err(WrapperError::Skip(_))
+        ));
+    }
+
+    #[test]
+    fn generate_anim_enable_argument() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_bar_set_value(obj: *mut lv_obj_t, value: i32, anim: lv_anim_enable_t);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let bar_set_value = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "bar".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = bar_set_value.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            pub fn set_value(&mut self, value: i32, anim: crate::AnimEnable) -> () {
+                unsafe {
+                    lvgl_sys::lv_bar_set_value(self.core.raw().as_mut(), value, anim.into());
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_must_use_only_for_getters() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_slider_get_value(obj: *const lv_obj_t) -> i32;
+                pub fn lv_slider_set_value(obj: *mut lv_obj_t, value: i32, anim: lv_anim_enable_t);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let parent_widget = LvWidget {
+            name: "slider".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let getter = cg[0].code(&parent_widget).unwrap().to_string();
+        assert!(getter.starts_with(&quote!(#[must_use]).to_string()));
+
+        let setter = cg[1].code(&parent_widget).unwrap().to_string();
+        assert!(!setter.contains("must_use"));
+    }
+
+    #[test]
+    fn generate_descriptor_wrapper_for_chart_series() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_chart_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_chart_add_series(
+                    obj: *mut lv_obj_t,
+                    color: lv_color_t,
+                    axis: lv_chart_axis_t,
+                ) -> *mut lv_chart_series_t;
+                pub fn lv_chart_set_next_value(
+                    obj: *mut lv_obj_t,
+                    ser: *mut lv_chart_series_t,
+                    value: lv_coord_t,
+                );
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+
+        let chart_series = cg.get_descriptors().first().unwrap();
+        let code = chart_series.code(&()).unwrap();
+        let expected_code = quote! {
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub struct ChartSeries {
+                raw: core::ptr::NonNull<lv_chart_series_t>,
+            }
+
+            impl ChartSeries {
+                pub fn raw(&self) -> core::ptr::NonNull<lv_chart_series_t> {
+                    self.raw
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        let parent_widget = LvWidget {
+            name: "chart".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = cg.functions[1].code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            pub fn add_series(&mut self, color: crate::Color, axis: lv_chart_axis_t) -> Option<ChartSeries> {
+                unsafe {
+                    let ptr = lvgl_sys::lv_chart_add_series(
+                        self.core.raw().as_mut(),
+                        color.raw,
+                        axis
+                    );
+                    core::ptr::NonNull::new(ptr).map(|raw| ChartSeries { raw })
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        let code = cg.functions[2].code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            pub fn set_next_value(&mut self, ser: ChartSeries, value: lv_coord_t) -> () {
+                unsafe {
+                    lvgl_sys::lv_chart_set_next_value(
+                        self.core.raw().as_mut(),
+                        ser.raw.as_ptr(),
+                        value
+                    );
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_without_descriptors() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_calendar_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_calendar_get_today_date(calendar: *const lv_obj_t) -> *const lv_calendar_date_t;
+                pub fn lv_calendar_get_pressed_date(obj: *const lv_obj_t, date: *mut lv_calendar_date_t) -> lv_res_t;
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+        assert_eq!(cg.get_descriptors().len(), 1);
+
+        let cg = cg.without_descriptors(&["lv_calendar_date_t"]);
+        assert!(cg.get_descriptors().is_empty());
+        assert!(!cg.code().to_string().contains("CalendarDate"));
+        assert_eq!(
+            cg.skipped(),
+            vec![(
+                "lv_calendar_get_today_date".to_string(),
+                SkipReason::PointerReturn("* const lv_calendar_date_t".to_string())
+            )]
+        );
+    }
+
+    #[test]
+    fn generate_with_overrides() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_roller_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_roller_get_selected(obj: *const lv_obj_t) -> u16;
+                pub fn lv_roller_get_option_cnt(obj: *const lv_obj_t) -> u16;
+                pub fn lv_roller_set_visible_row_count(obj: *mut lv_obj_t, row_cnt: u8);
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str())
+            .unwrap()
+            .with_overrides(&[
+                (
+                    "lv_roller_get_option_cnt",
+                    FuncOverride {
+                        rename: Some("get_option_count".to_string()),
+                        skip: false,
+                    },
+                ),
+                (
+                    "lv_roller_get_selected",
+                    FuncOverride {
+                        rename: None,
+                        skip: true,
+                    },
+                ),
+            ]);
+
+        let code = cg.code().to_string();
+        let renamed = quote!(pub fn get_option_count(&self) -> u16).to_string();
+        assert!(code.contains(&renamed));
+        assert!(code.contains("lvgl_sys :: lv_roller_get_option_cnt"));
+        assert!(!code.contains("fn get_option_cnt"));
+        assert!(!code.contains("get_selected"));
+        // Functions without an override are unchanged
+        assert!(code.contains("pub fn set_visible_row_count"));
+        assert_eq!(
+            cg.skipped(),
+            vec![("lv_roller_get_selected".to_string(), SkipReason::Hidden)]
+        );
+    }
+
+    #[test]
+    fn can_identify_widgets_sharing_a_prefix() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_btn_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_btnmatrix_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_btnmatrix_set_map(obj: *mut lv_obj_t, map: *mut *const cty::c_char);
+                pub fn lv_color_wheel_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+            }
+        };
+        let funcs = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let widget_names = CodeGen::get_widget_names(&funcs, &Default::default()).unwrap();
+        assert_eq!(widget_names, vec!["btn", "btnmatrix", "color_wheel"]);
+
+        let widgets = CodeGen::extract_widgets(&funcs, &widget_names).unwrap();
+        let btn = widgets.iter().find(|w| w.name == "btn").unwrap();
+        assert_eq!(btn.methods.len(), 1);
+        let btnmatrix = widgets.iter().find(|w| w.name == "btnmatrix").unwrap();
+        assert_eq!(btnmatrix.methods.len(), 2);
+    }
+
+    #[test]
+    fn widget_parts_are_not_widgets() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_calendar_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_calendar_header_arrow_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_menu_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_menu_cont_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+            }
+        };
+        let funcs = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let widget_names = CodeGen::get_widget_names(&funcs, &Default::default()).unwrap();
+        assert_eq!(widget_names, vec!["calendar", "menu"]);
+
+        let widgets = CodeGen::extract_widgets(&funcs, &widget_names).unwrap();
+        let menu = widgets.iter().find(|w| w.name == "menu").unwrap();
+        let names: Vec<&str> = menu.methods.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["lv_menu_create", "lv_menu_cont_create"]);
+    }
+
+    #[test]
+    fn configure_widget_constructors() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_btn_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_msgbox_new(parent: *mut lv_obj_t, title: *const cty::c_char) -> *mut lv_obj_t;
+            }
+        };
+        let funcs = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+        let constructors = WidgetConstructors {
+            pattern: "^lv_([a-z0-9_]+)_new$".to_string(),
+            args: 2,
+        };
+
+        let widget_names = CodeGen::get_widget_names(&funcs, &constructors).unwrap();
+        assert_eq!(widget_names, vec!["msgbox"]);
+        let invalid = WidgetConstructors {
+            pattern: "(".to_string(),
+            args: 1,
+        };
+        assert!(CodeGen::get_widget_names(&funcs, &invalid).is_err());
+    }
+
+    #[test]
+    fn generate_widget_without_default_constructor() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_menu_page_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str())
+            .unwrap()
+            .without_default_constructors(&["menu_page"]);
+
+        let code = cg.get_widgets().first().unwrap().code(&()).unwrap();
+        let expected_code = quote! {
+            define_object!(MenuPage);
+
+            impl<'a> MenuPage<'a> {
+                pub fn create(parent: &mut impl crate::NativeObject) -> crate::LvResult<Self> {
+                    if !crate::is_init() {
+                        return Err(crate::LvError::Uninitialized);
+                    }
+                    unsafe {
+                        let ptr = lvgl_sys::lv_menu_page_create(
+                            parent.raw().as_mut(),
+                        );
+                        if let Some(raw) = core::ptr::NonNull::new(ptr) {
+                            let core = <crate::Obj as Widget>::from_raw(raw).unwrap();
+                            Ok(Self { core })
+                        } else {
+                            Err(crate::LvError::LvOOMemory)
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_widget_with_hidden_function() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_chart_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_chart_set_point_count(obj: *mut lv_obj_t, cnt: u16);
+                pub fn lv_chart_set_ext_y_array(
+                    obj: *mut lv_obj_t,
+                    ser: *mut lv_chart_series_t,
+                    array: *mut lv_coord_t,
+                );
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str())
+            .unwrap()
+            .with_overrides(&[(
+                "lv_chart_set_ext_y_array",
+                FuncOverride {
+                    skip: true,
+                    ..Default::default()
+                },
+            )]);
+
+        let chart = cg.get_widgets().first().unwrap();
+        let code = chart.code(&()).unwrap().to_string();
+        assert!(code.contains("pub fn set_point_count"));
+        assert!(!code.contains("set_ext_y_array"));
+        assert_eq!(
+            cg.skipped(),
+            vec![("lv_chart_set_ext_y_array".to_string(), SkipReason::Hidden)]
+        );
+    }
+
+    #[test]
+    fn generate_method_wrapper_for_str_return() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_textarea_get_text(obj: *const lv_obj_t) -> *const cty::c_char;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let textarea_get_text = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "textarea".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = textarea_get_text.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            #[must_use]
+            pub fn get_text(&self) -> Option<&cstr_core::CStr> {
+                unsafe {
+                    let ptr = lvgl_sys::lv_textarea_get_text(
+                        self.core.raw().as_ptr()
+                    );
+                    if ptr.is_null() {
+                        None
+                    } else {
+                        Some(cstr_core::CStr::from_ptr(ptr))
+                    }
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_widget_kinds_and_prelude() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_obj_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_label_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_arc_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+
+        let code = cg.code().to_string();
+        let expected_kinds = quote! {
+            #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+            pub enum WidgetKind {
+                Arc,
+                Label,
+            }
+        };
+        let expected_prelude = quote! {
+            pub mod prelude {
+                pub use super::{CommonWidgetExt, Widget, WidgetKind, Arc, Label};
+            }
+        };
+        assert!(code.contains(&expected_kinds.to_string()));
+        assert!(code.ends_with(&expected_prelude.to_string()));
+    }
+
+    #[test]
+    fn generate_common_widget_ext_for_all_objects() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_obj_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_obj_align(obj: *mut lv_obj_t, align: lv_align_t, x_ofs: lv_coord_t, y_ofs: lv_coord_t);
+                pub fn lv_label_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+
+        let code = cg.code().to_string();
+        let expected_align = quote! {
+            fn align(&mut self, align: lv_align_t, x_ofs: lv_coord_t, y_ofs: lv_coord_t) -> () {
+                unsafe {
+                    lvgl_sys::lv_obj_align(self.raw().as_mut(), align, x_ofs, y_ofs);
+                }
+            }
+        };
+        let expected_impl = quote! {
+            impl<T: NativeObject> CommonWidgetExt for T {}
+        };
+        let trait_start = code
+            .find(&quote!(pub trait CommonWidgetExt: NativeObject).to_string())
+            .unwrap();
+        let trait_end = code.find(&expected_impl.to_string()).unwrap();
+        // Label only gets its constructor; `align` comes from the blanket impl
+        assert!(code[trait_start..trait_end].contains(&expected_align.to_string()));
+        assert_eq!(code.matches(&expected_align.to_string()).count(), 1);
+        assert!(code.contains(&quote!(define_object!(Label);).to_string()));
+    }
+
+    #[test]
+    fn list_skipped_functions() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_arc_create(parent: *mut lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_arc_set_value(obj: *mut lv_obj_t, value: i16);
+                pub fn lv_arc_get_value(obj: *const lv_obj_t) -> i16;
+                pub fn lv_arc_get_dsc(obj: *const lv_obj_t) -> *mut lv_draw_arc_dsc_t;
+                pub fn lv_arc_set_points(obj: *mut lv_obj_t, points: *mut *mut lv_point_t);
+                pub fn lv_arc_set_cb(
+                    obj: *mut lv_obj_t,
+                    cb: ::core::option::Option<unsafe extern "C" fn(obj: *mut lv_obj_t)>,
+                );
+                pub fn lv_arc_set_data(obj: *mut lv_obj_t, data: *mut cty::c_void);
+                pub fn lv_arc_set_pair(obj: *mut lv_obj_t, pair: (i16, i16));
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+
+        let skipped = cg.skipped();
+        let skipped: Vec<(&str, SkipReason)> = skipped
+            .iter()
+            .map(|(name, reason)| (name.as_str(), reason.clone()))
+            .collect();
+        assert_eq!(
+            skipped,
+            vec![
+                (
+                    "lv_arc_get_dsc",
+                    SkipReason::PointerReturn("* mut lv_draw_arc_dsc_t".to_string())
+                ),
+                (
+                    "lv_arc_set_cb",
+                    SkipReason::CallbackArgument(
+                        ":: core :: option :: Option < unsafe extern \"C\" fn (obj : * mut lv_obj_t) >"
+                            .to_string()
+                    )
+                ),
+                (
+                    "lv_arc_set_data",
+                    SkipReason::VoidPointerArgument("* mut cty :: c_void".to_string())
+                ),
+                (
+                    "lv_arc_set_pair",
+                    SkipReason::UnparseableType("(i16 , i16)".to_string())
+                ),
+                (
+                    "lv_arc_set_points",
+                    SkipReason::ArrayArgument("* mut * mut lv_point_t".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn escape_keyword_method_names() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_arc_type(obj: *const lv_obj_t) -> u8;
+                pub fn lv_arc_match(obj: *mut lv_obj_t, value: u8);
+                pub fn lv_arc_self(obj: *mut lv_obj_t);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+        let parent_widget = LvWidget {
+            name: "arc".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code: Vec<String> = cg
+            .iter()
+            .map(|f| f.code(&parent_widget).unwrap().to_string())
+            .collect();
+        let expected_type = quote! {
+            pub fn r#type(&self) -> u8 {
+                unsafe {
+                    lvgl_sys::lv_arc_type(self.core.raw().as_ptr())
+                }
+            }
+        };
+        assert_eq!(code[0], expected_type.to_string());
+        assert!(code[1].starts_with(&quote!(pub fn r#match(&mut self, value: u8)).to_string()));
+        assert!(code[2].starts_with(&quote!(pub fn self_(&mut self)).to_string()));
+    }
+
+    #[test]
+    fn generate_method_wrapper_for_two_mut_str_arguments() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_textarea_swap(
+                    obj: *mut lv_obj_t,
+                    first: *mut cty::c_char,
+                    type_: *mut cty::c_char,
+                );
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+        let parent_widget = LvWidget {
+            name: "textarea".to_string(),
+            methods: vec![],
+            default_constructor: true,
+        };
+
+        let code = cg[0].code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            pub fn swap(
+                &mut self,
+                first: &mut cstr_core::CString,
+                type_: &mut cstr_core::CString
+            ) -> () {
+                unsafe {
+                    let first_raw = first.clone().into_raw();
+                    let type__raw = type_.clone().into_raw();
+                    lvgl_sys::lv_textarea_swap(self.core.raw().as_mut(), first_raw, type__raw);
+                    *first = cstr_core::CString::from_raw(first_raw);
+                    *type_ = cstr_core::CString::from_raw(type__raw);
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
 }
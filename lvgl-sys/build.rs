@@ -232,7 +232,7 @@ fn main() {
     //#[cfg(feature = "rust_timer")]
     //let bindings = bindings.header(shims_dir.join("rs_timer.h").to_str().unwrap());
     let bindings = bindings
-        .generate_comments(false)
+        .generate_comments(true)
         .derive_default(true)
         .layout_tests(false)
         .use_core()
@@ -24,3 +24,100 @@ uint16_t _LV_COLOR_GET_A(lv_color_t color)
 {
     return LV_COLOR_GET_A(color);
 }
+
+uint32_t _LV_COLOR_TO32(lv_color_t color)
+{
+    return lv_color_to32(color);
+}
+
+const lv_font_t * _LV_FONT_MONTSERRAT(uint8_t size)
+{
+    switch(size) {
+#if LV_FONT_MONTSERRAT_8
+        case 8:
+            return &lv_font_montserrat_8;
+#endif
+#if LV_FONT_MONTSERRAT_10
+        case 10:
+            return &lv_font_montserrat_10;
+#endif
+#if LV_FONT_MONTSERRAT_12
+        case 12:
+            return &lv_font_montserrat_12;
+#endif
+#if LV_FONT_MONTSERRAT_14
+        case 14:
+            return &lv_font_montserrat_14;
+#endif
+#if LV_FONT_MONTSERRAT_16
+        case 16:
+            return &lv_font_montserrat_16;
+#endif
+#if LV_FONT_MONTSERRAT_18
+        case 18:
+            return &lv_font_montserrat_18;
+#endif
+#if LV_FONT_MONTSERRAT_20
+        case 20:
+            return &lv_font_montserrat_20;
+#endif
+#if LV_FONT_MONTSERRAT_22
+        case 22:
+            return &lv_font_montserrat_22;
+#endif
+#if LV_FONT_MONTSERRAT_24
+        case 24:
+            return &lv_font_montserrat_24;
+#endif
+#if LV_FONT_MONTSERRAT_26
+        case 26:
+            return &lv_font_montserrat_26;
+#endif
+#if LV_FONT_MONTSERRAT_28
+        case 28:
+            return &lv_font_montserrat_28;
+#endif
+#if LV_FONT_MONTSERRAT_30
+        case 30:
+            return &lv_font_montserrat_30;
+#endif
+#if LV_FONT_MONTSERRAT_32
+        case 32:
+            return &lv_font_montserrat_32;
+#endif
+#if LV_FONT_MONTSERRAT_34
+        case 34:
+            return &lv_font_montserrat_34;
+#endif
+#if LV_FONT_MONTSERRAT_36
+        case 36:
+            return &lv_font_montserrat_36;
+#endif
+#if LV_FONT_MONTSERRAT_38
+        case 38:
+            return &lv_font_montserrat_38;
+#endif
+#if LV_FONT_MONTSERRAT_40
+        case 40:
+            return &lv_font_montserrat_40;
+#endif
+#if LV_FONT_MONTSERRAT_42
+        case 42:
+            return &lv_font_montserrat_42;
+#endif
+#if LV_FONT_MONTSERRAT_44
+        case 44:
+            return &lv_font_montserrat_44;
+#endif
+#if LV_FONT_MONTSERRAT_46
+        case 46:
+            return &lv_font_montserrat_46;
+#endif
+#if LV_FONT_MONTSERRAT_48
+        case 48:
+            return &lv_font_montserrat_48;
+#endif
+        default:
+            return NULL;
+    }
+}
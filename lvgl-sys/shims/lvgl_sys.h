@@ -12,6 +12,8 @@ uint16_t _LV_COLOR_GET_R(lv_color_t color);
 uint16_t _LV_COLOR_GET_G(lv_color_t color);
 uint16_t _LV_COLOR_GET_B(lv_color_t color);
 uint16_t _LV_COLOR_GET_A(lv_color_t color);
+uint32_t _LV_COLOR_TO32(lv_color_t color);
+const lv_font_t * _LV_FONT_MONTSERRAT(uint8_t size);
 
 
 #ifdef __cplusplus
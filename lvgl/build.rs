@@ -1,27 +1,100 @@
-use lvgl_codegen::{CodeGen, Rusty};
-use proc_macro2::TokenStream;
+use lvgl_codegen::{CodeGen, FuncOverride};
 use quote::quote;
 use std::env;
 use std::fs::File;
 use std::io::prelude::*;
 use std::path::PathBuf;
 
+// Typed by hand in `NativeObject`, as `set_style_*()`
+include!("src/lv_core/local_style_props.rs");
+
+macro_rules! prop_names {
+    ($($prop:ident: $ty:ty),* $(,)?) => {
+        &[$(stringify!($prop)),*]
+    };
+}
+
+const LOCAL_STYLE_PROPS: &[&str] = with_local_style_props!(prop_names);
+
+/// Hides the generated wrappers of the given C functions.
+fn hidden<'a>(function_names: &[&'a str]) -> Vec<(&'a str, FuncOverride)> {
+    function_names
+        .iter()
+        .map(|name| {
+            (
+                *name,
+                FuncOverride {
+                    skip: true,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect()
+}
+
 fn main() {
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     let rs = out_path.join("generated.rs");
 
     let widgets_impl = lvgl_sys::_bindgen_raw_src();
 
-    let codegen = CodeGen::from(widgets_impl).unwrap();
-    let widgets_impl: Vec<TokenStream> = codegen
-        .get_widgets()
+    let codegen = CodeGen::from(widgets_impl)
+        .unwrap()
+        // Plain values, wrapped by hand in `widgets`
+        .without_descriptors(&["lv_calendar_date_t"])
+        // Wrapped by hand in `widgets`, with safer signatures
+        .with_overrides(&hidden(&[
+            "lv_bar_set_start_value",
+            "lv_bar_set_value",
+            "lv_btnmatrix_set_ctrl_map",
+            "lv_btnmatrix_set_map",
+            "lv_calendar_get_pressed_date",
+            "lv_calendar_set_highlighted_dates",
+            "lv_canvas_fill_bg",
+            "lv_chart_set_ext_y_array",
+            "lv_chart_set_point_count",
+            "lv_checkbox_get_text",
+            "lv_dropdown_get_selected_str",
+            "lv_list_add_text",
+            "lv_obj_add_flag",
+            "lv_obj_add_state",
+            "lv_obj_add_style",
+            "lv_obj_align",
+            "lv_obj_align_to",
+            "lv_obj_clean",
+            "lv_obj_clear_flag",
+            "lv_obj_clear_state",
+            "lv_obj_del",
+            "lv_obj_has_flag",
+            "lv_obj_has_state",
+            "lv_obj_remove_style",
+            "lv_obj_report_style_change",
+            "lv_obj_set_flex_flow",
+            "lv_obj_set_grid_cell",
+            "lv_obj_set_grid_dsc_array",
+            "lv_obj_set_height",
+            "lv_obj_set_size",
+            "lv_obj_set_width",
+            "lv_roller_get_selected_str",
+            "lv_roller_set_selected",
+            "lv_spinbox_get_value",
+            "lv_textarea_get_text",
+        ]));
+    let local_styles: Vec<String> = LOCAL_STYLE_PROPS
         .iter()
-        .flat_map(|w| w.code(&()))
+        .map(|prop| format!("lv_obj_set_style_{}", prop))
         .collect();
+    let codegen = codegen.with_overrides(&hidden(
+        &local_styles
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>(),
+    ));
+    let generated = codegen.code();
 
     let code = quote! {
         use lvgl_sys::*;
-        #(#widgets_impl)*
+        #generated
     };
 
     let mut file = File::create(rs).unwrap();
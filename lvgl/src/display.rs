@@ -1,14 +1,17 @@
 use crate::functions::CoreError;
-use crate::Screen;
+use crate::widgets::ScrLoadAnim;
 use crate::{disp_drv_register, disp_get_default, get_str_act, NativeObject};
 use crate::{Box, Color};
+use crate::{Point, Screen};
 use core::convert::TryInto;
 #[cfg(feature = "nightly")]
 use core::error::Error;
 use core::fmt;
-use core::mem::{ManuallyDrop, MaybeUninit};
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::num::TryFromIntError;
 use core::pin::Pin;
 use core::ptr::NonNull;
+use core::time::Duration;
 use core::{ptr, result};
 
 /// Error in interacting with a `Display`.
@@ -63,6 +66,9 @@ impl<'a> Display {
     where
         F: FnMut(&DisplayRefresh<N>) + 'a,
     {
+        if !crate::is_init() {
+            return Err(DisplayError::NotAvailable);
+        }
         let mut display_diver = DisplayDriver::new(draw_buffer, display_update)?;
         let disp_p = &mut display_diver.disp_drv;
         disp_p.hor_res = hor_res.try_into().unwrap_or(240);
@@ -76,12 +82,55 @@ impl<'a> Display {
         Ok(get_str_act(Some(self))?.try_into()?)
     }
 
+    /// Makes this the default display, which new screens and widgets are
+    /// created on.
+    pub fn set_default(&self) {
+        unsafe { lvgl_sys::lv_disp_set_default(self.disp.as_ptr()) }
+    }
+
     /// Sets a `Screen` as currently active.
     pub fn set_scr_act(&'a self, screen: &'a mut Screen) {
         let scr_ptr = unsafe { screen.raw().as_mut() };
         unsafe { lvgl_sys::lv_disp_load_scr(scr_ptr) }
     }
 
+    /// Loads a `Screen` with an animation lasting `time`, which starts after
+    /// `delay`. With `auto_del`, the previous screen is deleted once the
+    /// animation ends and must not be used anymore.
+    pub fn load_scr_anim(
+        &'a self,
+        screen: &'a mut Screen,
+        anim: ScrLoadAnim,
+        time: Duration,
+        delay: Duration,
+        auto_del: bool,
+    ) -> result::Result<(), TryFromIntError> {
+        let time = time.as_millis().try_into()?;
+        let delay = delay.as_millis().try_into()?;
+        unsafe {
+            lvgl_sys::lv_scr_load_anim(screen.raw().as_mut(), anim.into(), time, delay, auto_del)
+        }
+        Ok(())
+    }
+
+    /// Returns the horizontal resolution of the display, taking its rotation
+    /// into account.
+    pub fn hor_res(&self) -> i16 {
+        unsafe { lvgl_sys::lv_disp_get_hor_res(self.disp.as_ptr()) }
+    }
+
+    /// Returns the vertical resolution of the display, taking its rotation
+    /// into account.
+    pub fn ver_res(&self) -> i16 {
+        unsafe { lvgl_sys::lv_disp_get_ver_res(self.disp.as_ptr()) }
+    }
+
+    /// Rotates the display. The flush callback keeps receiving areas in
+    /// rotated coordinates, so the driver has to handle the rotation itself.
+    pub fn set_rotation(&self, rot: DisplayRotation) {
+        unsafe { lvgl_sys::lv_disp_set_rotation(self.disp.as_ptr(), rot.into()) }
+    }
+
     /// Registers a display from raw functions and values.
     ///
     /// # Safety
@@ -122,6 +171,9 @@ impl<'a> Display {
         render_start_cb: Option<unsafe extern "C" fn(*mut lvgl_sys::lv_disp_drv_t)>,
         drop: Option<unsafe extern "C" fn()>,
     ) -> Result<Self> {
+        if !crate::is_init() {
+            return Err(DisplayError::NotAvailable);
+        }
         let mut display_driver = DisplayDriver::new_raw(
             draw_buffer,
             flush_cb,
@@ -155,9 +207,64 @@ impl Drop for Display {
     }
 }
 
-/// Gets the active screen of the default display.
-pub(crate) fn get_scr_act() -> Result<Screen<'static>> {
-    Ok(get_str_act(None)?.try_into()?)
+/// The rotation of a `Display`, clockwise.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DisplayRotation {
+    None,
+    Rot90,
+    Rot180,
+    Rot270,
+}
+
+impl From<DisplayRotation> for lvgl_sys::lv_disp_rot_t {
+    fn from(value: DisplayRotation) -> Self {
+        match value {
+            DisplayRotation::None => lvgl_sys::lv_disp_rot_t_LV_DISP_ROT_NONE,
+            DisplayRotation::Rot90 => lvgl_sys::lv_disp_rot_t_LV_DISP_ROT_90,
+            DisplayRotation::Rot180 => lvgl_sys::lv_disp_rot_t_LV_DISP_ROT_180,
+            DisplayRotation::Rot270 => lvgl_sys::lv_disp_rot_t_LV_DISP_ROT_270,
+        }
+    }
+}
+
+/// Gets the active screen of `disp`, or of the default display if `None`.
+pub(crate) fn get_scr_act(disp: Option<&Display>) -> Result<Screen<'static>> {
+    Ok(get_str_act(disp)?.try_into()?)
+}
+
+/// A pixel format LVGL can render in. Which one is used is picked by
+/// `LV_COLOR_DEPTH` at build time, see `ColorFormat::native()`, and
+/// `DrawBuffer` is always sized for it. LVGL 8 can't render 24-bit colors,
+/// so panels that take `RGB888` or any other format need the flush callback
+/// to convert the colors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ColorFormat {
+    I1,
+    RGB332,
+    RGB565,
+    ARGB8888,
+}
+
+impl ColorFormat {
+    /// Returns the format LVGL renders in.
+    pub fn native() -> Self {
+        match lvgl_sys::LV_COLOR_DEPTH {
+            1 => ColorFormat::I1,
+            8 => ColorFormat::RGB332,
+            16 => ColorFormat::RGB565,
+            _ => ColorFormat::ARGB8888,
+        }
+    }
+
+    /// Returns the size of a rendered pixel in bytes. Monochrome pixels are
+    /// still rendered into a whole byte each.
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            ColorFormat::I1 | ColorFormat::RGB332 => 1,
+            ColorFormat::RGB565 => 2,
+            ColorFormat::ARGB8888 => 4,
+        }
+    }
 }
 
 /// A buffer of size `N` representing `N` pixels. `N` can be smaller than the
@@ -166,29 +273,50 @@ pub(crate) fn get_scr_act() -> Result<Screen<'static>> {
 pub struct DrawBuffer<const N: usize> {
     draw_buf: Pin<Box<lvgl_sys::lv_disp_draw_buf_t>>,
     _refresh_buffer: Pin<Box<[MaybeUninit<lvgl_sys::lv_color_t>; N]>>,
+    _second_buffer: Option<Pin<Box<[MaybeUninit<lvgl_sys::lv_color_t>; N]>>>,
 }
 
 impl<const N: usize> Default for DrawBuffer<N> {
     fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+impl<const N: usize> DrawBuffer<N> {
+    /// Creates two buffers of `N` pixels each, so LVGL can render into one
+    /// while the other is being flushed. This takes twice the memory and
+    /// only pays off if the flush finishes asynchronously, e.g. with a DMA
+    /// transfer set up through `Display::register_raw()` that calls
+    /// `lv_disp_flush_ready()` once done. Flushes through
+    /// `Display::register()` copy the pixels out before returning, so a
+    /// single buffer performs the same there.
+    pub fn double() -> Self {
+        Self::new(true)
+    }
+
+    fn new(double: bool) -> Self {
         let mut buf = Box::pin([MaybeUninit::uninit(); N]);
+        let mut second_buf = double.then(|| Box::pin([MaybeUninit::uninit(); N]));
         Self {
             draw_buf: Box::pin(unsafe {
                 let mut inner: MaybeUninit<lvgl_sys::lv_disp_draw_buf_t> = MaybeUninit::uninit();
                 let raw_ptr = buf.as_mut_ptr() as *mut _;
-                lvgl_sys::lv_disp_draw_buf_init(
-                    inner.as_mut_ptr(),
-                    raw_ptr,
-                    ptr::null_mut(),
-                    N as u32,
-                );
+                let second_ptr = second_buf
+                    .as_mut()
+                    .map_or(ptr::null_mut(), |b| b.as_mut_ptr() as *mut _);
+                lvgl_sys::lv_disp_draw_buf_init(inner.as_mut_ptr(), raw_ptr, second_ptr, N as u32);
                 inner.assume_init()
             }),
             _refresh_buffer: buf,
+            _second_buffer: second_buf,
         }
     }
-}
 
-impl<const N: usize> DrawBuffer<N> {
+    /// Returns the size of a single buffer in bytes.
+    pub fn size_in_bytes(&self) -> usize {
+        N * mem::size_of::<lvgl_sys::lv_color_t>()
+    }
+
     fn get_ptr(&mut self) -> &mut lvgl_sys::lv_disp_draw_buf_t {
         &mut self.draw_buf
     }
@@ -288,7 +416,9 @@ impl<'a, const N: usize> DisplayDriver<N> {
     }
 }
 
-/// Represents a sub-area of the display that is being updated.
+/// Represents a sub-area of the display that is being updated. Both corners
+/// are inclusive.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Area {
     pub x1: i16,
     pub x2: i16,
@@ -296,9 +426,75 @@ pub struct Area {
     pub y2: i16,
 }
 
+impl Area {
+    /// Creates the area from `(x1, y1)` to `(x2, y2)`, both included.
+    pub const fn new(x1: i16, y1: i16, x2: i16, y2: i16) -> Self {
+        Self { x1, x2, y1, y2 }
+    }
+
+    /// Returns the width of the area in pixels.
+    pub fn width(&self) -> i16 {
+        self.x2 - self.x1 + 1
+    }
+
+    /// Returns the height of the area in pixels.
+    pub fn height(&self) -> i16 {
+        self.y2 - self.y1 + 1
+    }
+
+    /// Returns whether `point` lies in the area or on its edges.
+    pub fn contains(&self, point: Point) -> bool {
+        let (Ok(x), Ok(y)) = (point.x.try_into(), point.y.try_into()) else {
+            return false;
+        };
+        let point = lvgl_sys::lv_point_t { x, y };
+        unsafe { lvgl_sys::_lv_area_is_point_on(&(*self).into(), &point, 0) }
+    }
+
+    /// Returns the area covered by both `self` and `other`, or `None` if
+    /// they don't overlap. Areas sharing an edge overlap on that edge.
+    pub fn intersect(&self, other: &Area) -> Option<Area> {
+        let mut res = MaybeUninit::<lvgl_sys::lv_area_t>::uninit();
+        unsafe {
+            lvgl_sys::_lv_area_intersect(res.as_mut_ptr(), &(*self).into(), &(*other).into())
+                .then(|| res.assume_init().into())
+        }
+    }
+}
+
+impl From<lvgl_sys::lv_area_t> for Area {
+    fn from(value: lvgl_sys::lv_area_t) -> Self {
+        Self::new(value.x1, value.y1, value.x2, value.y2)
+    }
+}
+
+impl From<Area> for lvgl_sys::lv_area_t {
+    fn from(value: Area) -> Self {
+        Self {
+            x1: value.x1,
+            y1: value.y1,
+            x2: value.x2,
+            y2: value.y2,
+        }
+    }
+}
+
 /// An update to the display information, contains the area that is being
 /// updated and the color of the pixels that need to be updated. The colors
-/// are represented in a contiguous array.
+/// are represented in a contiguous array, row by row, and only the first
+/// `area.width() * area.height()` of them belong to the update.
+///
+/// Drivers that stream pixels to a window, like most SPI panels, can set
+/// the window from `area` before pushing `colors`:
+///
+/// ```ignore
+/// Display::register(buffer, 240, 240, |refresh| {
+///     let Area { x1, y1, x2, y2 } = refresh.area;
+///     panel.set_window(x1, y1, x2, y2);
+///     let len = (refresh.area.width() * refresh.area.height()) as usize;
+///     panel.write_pixels(&refresh.colors[..len]);
+/// })
+/// ```
 pub struct DisplayRefresh<const N: usize> {
     pub area: Area,
     pub colors: [Color; N],
@@ -356,12 +552,7 @@ unsafe extern "C" fn disp_flush_trampoline<'a, F, const N: usize>(
         }
 
         let update = DisplayRefresh {
-            area: Area {
-                x1: (*area).x1,
-                x2: (*area).x2,
-                y1: (*area).y1,
-                y2: (*area).y2,
-            },
+            area: (*area).into(),
             colors,
         };
         callback(&update);
@@ -378,6 +569,7 @@ impl From<CoreError> for DisplayError {
         match err {
             CoreError::ResourceNotAvailable => NotAvailable,
             CoreError::OperationFailed => NotAvailable,
+            CoreError::OutOfMemory => FailedToRegister,
         }
     }
 }
@@ -386,6 +578,9 @@ impl From<CoreError> for DisplayError {
 mod tests {
     use super::*;
     use crate::tests;
+    use core::mem;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
     fn get_scr_act_return_display() {
@@ -400,7 +595,7 @@ mod tests {
         let _screen_direct = display
             .get_scr_act()
             .expect("Return screen directly from the display instance");
-        let _screen_default = get_scr_act().expect("Return screen from the default display");
+        let _screen_default = get_scr_act(None).expect("Return screen from the default display");
     }
 
     #[test]
@@ -412,4 +607,168 @@ mod tests {
             .expect("Return screen directly from the display instance");
         Ok(())
     }
+
+    #[test]
+    fn load_scr_anim_fade() {
+        tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen_old = display.get_scr_act().unwrap();
+        let mut screen_new = Screen::blank().unwrap();
+        display
+            .load_scr_anim(
+                &mut screen_new,
+                ScrLoadAnim::FadeIn,
+                Duration::from_millis(100),
+                Duration::ZERO,
+                false,
+            )
+            .unwrap();
+        for _ in 0..5 {
+            crate::tick_inc(Duration::from_millis(50));
+            crate::task_handler();
+        }
+        assert_eq!(display.get_scr_act().unwrap().raw(), screen_new.raw());
+        assert!(unsafe { display.disp.as_ref().prev_scr.is_null() });
+
+        display.set_scr_act(&mut screen_old);
+        assert_eq!(display.get_scr_act().unwrap().raw(), screen_old.raw());
+        screen_new.delete();
+    }
+
+    #[test]
+    fn set_rotation_swaps_resolution() {
+        tests::initialize_test(false);
+        let buffer = DrawBuffer::<{ 320 * 24 }>::default();
+        let display = Display::register(buffer, 320, 240, |_| {}).unwrap();
+        assert_eq!((display.hor_res(), display.ver_res()), (320, 240));
+
+        display.set_rotation(DisplayRotation::Rot90);
+        assert_eq!((display.hor_res(), display.ver_res()), (240, 320));
+
+        display.set_rotation(DisplayRotation::None);
+        assert_eq!((display.hor_res(), display.ver_res()), (320, 240));
+    }
+
+    #[test]
+    fn register_two_displays() {
+        tests::initialize_test(true);
+        let first = Display::default();
+        let buffer = DrawBuffer::<{ 320 * 24 }>::default();
+        let second = Display::register(buffer, 320, 240, |_| {}).unwrap();
+
+        let first_screen = first.get_scr_act().unwrap();
+        let second_screen = second.get_scr_act().unwrap();
+        assert_ne!(first_screen.raw(), second_screen.raw());
+        assert_eq!(get_scr_act(None).unwrap().raw(), first_screen.raw());
+
+        second.set_default();
+        assert_eq!(get_scr_act(None).unwrap().raw(), second_screen.raw());
+        first.set_default();
+    }
+
+    #[test]
+    fn double_draw_buffer() {
+        tests::initialize_test(false);
+        const BUF_SIZE: usize = 240 * 24;
+        let mut buffer = DrawBuffer::<BUF_SIZE>::double();
+        let draw_buf = buffer.get_ptr();
+        assert_eq!(draw_buf.size, BUF_SIZE as u32);
+        assert!(!draw_buf.buf1.is_null());
+        assert!(!draw_buf.buf2.is_null());
+        assert_ne!(draw_buf.buf1, draw_buf.buf2);
+
+        let _display = Display::register(buffer, 240, 240, |_| {}).unwrap();
+        crate::task_handler();
+    }
+
+    #[test]
+    fn flush_reports_invalidated_area() {
+        tests::initialize_test(false);
+        let areas = Rc::new(RefCell::new(Vec::new()));
+        let flushed = areas.clone();
+        let buffer = DrawBuffer::<{ 240 * 24 }>::default();
+        let display = Display::register(buffer, 240, 240, move |refresh| {
+            flushed.borrow_mut().push(refresh.area);
+        })
+        .unwrap();
+        unsafe { lvgl_sys::lv_refr_now(display.disp.as_ptr()) };
+        areas.borrow_mut().clear();
+
+        let area = lvgl_sys::lv_area_t {
+            x1: 10,
+            y1: 20,
+            x2: 49,
+            y2: 59,
+        };
+        unsafe {
+            lvgl_sys::_lv_inv_area(display.disp.as_ptr(), &area);
+            lvgl_sys::lv_refr_now(display.disp.as_ptr());
+        }
+        let expected = Area {
+            x1: 10,
+            x2: 49,
+            y1: 20,
+            y2: 59,
+        };
+        assert_eq!(*areas.borrow(), [expected]);
+        assert_eq!((expected.width(), expected.height()), (40, 40));
+    }
+
+    #[test]
+    fn color_format_sizes() {
+        tests::initialize_test(false);
+        const BUF_SIZE: usize = 240 * 24;
+        assert_eq!(ColorFormat::ARGB8888.bytes_per_pixel(), 4);
+        assert_eq!(ColorFormat::RGB565.bytes_per_pixel(), 2);
+        assert_eq!(ColorFormat::I1.bytes_per_pixel(), 1);
+        assert_eq!(
+            ColorFormat::native().bytes_per_pixel(),
+            mem::size_of::<lvgl_sys::lv_color_t>()
+        );
+
+        let buffer = DrawBuffer::<BUF_SIZE>::default();
+        assert_eq!(
+            buffer.size_in_bytes(),
+            BUF_SIZE * mem::size_of::<lvgl_sys::lv_color_t>()
+        );
+    }
+
+    #[test]
+    fn area_size() {
+        let area = Area::new(10, 20, 19, 24);
+        assert_eq!((area.width(), area.height()), (10, 5));
+        assert_eq!(Area::new(3, 3, 3, 3).width(), 1);
+    }
+
+    #[test]
+    fn area_contains() {
+        let area = Area::new(0, 0, 9, 9);
+        assert!(area.contains(Point::new(5, 5)));
+        assert!(area.contains(Point::new(0, 0)));
+        assert!(area.contains(Point::new(9, 9)));
+        assert!(!area.contains(Point::new(10, 9)));
+        assert!(!area.contains(Point::new(-1, 0)));
+        assert!(!area.contains(Point::new(i32::MAX, 0)));
+    }
+
+    #[test]
+    fn area_intersect() {
+        let area = Area::new(0, 0, 9, 9);
+        assert_eq!(
+            area.intersect(&Area::new(5, -5, 20, 5)),
+            Some(Area::new(5, 0, 9, 5))
+        );
+        assert_eq!(area.intersect(&area), Some(area));
+        // Touching edges overlap by a single line of pixels
+        assert_eq!(
+            area.intersect(&Area::new(9, 0, 19, 9)),
+            Some(Area::new(9, 0, 9, 9))
+        );
+        assert_eq!(
+            area.intersect(&Area::new(9, 9, 19, 19)),
+            Some(Area::new(9, 9, 9, 9))
+        );
+        assert_eq!(area.intersect(&Area::new(10, 0, 19, 9)), None);
+        assert_eq!(area.intersect(&Area::new(0, 10, 9, 19)), None);
+    }
 }
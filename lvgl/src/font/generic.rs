@@ -1,13 +1,32 @@
-use crate::Box;
+use crate::{Box, LvError, LvResult};
+use core::mem::ManuallyDrop;
+use cstr_core::CStr;
 
 /// An LVGL font. Can be applied to styles using `set_text_font()`.
 pub struct Font {
-    inner: Box<lvgl_sys::lv_font_t>,
+    inner: ManuallyDrop<Box<lvgl_sys::lv_font_t>>,
+    loaded: bool,
 }
 
 impl From<Font> for *const lvgl_sys::lv_font_t {
     fn from(value: Font) -> Self {
-        Box::<lvgl_sys::lv_font_t>::into_raw(value.inner)
+        // Styles keep pointing to the font, so it must never be freed
+        let mut font = ManuallyDrop::new(value);
+        Box::<lvgl_sys::lv_font_t>::into_raw(unsafe { ManuallyDrop::take(&mut font.inner) })
+    }
+}
+
+impl Drop for Font {
+    fn drop(&mut self) {
+        unsafe {
+            if self.loaded {
+                // Frees the glyph data along with the font itself
+                let raw = Box::into_raw(ManuallyDrop::take(&mut self.inner));
+                lvgl_sys::lv_font_free(raw);
+            } else {
+                ManuallyDrop::drop(&mut self.inner);
+            }
+        }
     }
 }
 
@@ -18,7 +37,54 @@ impl Font {
     /// constructs it.
     pub unsafe fn new_raw(raw: lvgl_sys::lv_font_t) -> Self {
         Font {
-            inner: Box::new(raw),
+            inner: ManuallyDrop::new(Box::new(raw)),
+            loaded: false,
+        }
+    }
+
+    /// Loads a font in LVGL's binary format from `path`, e.g.
+    /// `"S:fonts/roboto_16.bin"`. This needs a filesystem driver to be
+    /// enabled in `lv_conf.h` (such as `LV_USE_FS_STDIO`), and fails with
+    /// `LvError::InvalidReference` if none can open the file. The font is
+    /// freed on drop unless it was given to a style.
+    pub fn load(path: &CStr) -> LvResult<Self> {
+        let raw = unsafe { lvgl_sys::lv_font_load(path.as_ptr()) };
+        if raw.is_null() {
+            return Err(LvError::InvalidReference);
+        }
+        Ok(Font {
+            inner: ManuallyDrop::new(unsafe { Box::from_raw(raw) }),
+            loaded: true,
+        })
+    }
+
+    /// Returns the built-in Montserrat font of the given `size`, or `None` if
+    /// that size isn't enabled in `lv_conf.h`.
+    pub fn montserrat(size: u8) -> Option<Self> {
+        let raw = unsafe { lvgl_sys::_LV_FONT_MONTSERRAT(size) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(unsafe { Self::new_raw(*raw) })
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn montserrat_sizes() {
+        crate::tests::initialize_test(false);
+        assert!(Font::montserrat(14).is_some());
+        assert!(Font::montserrat(13).is_none());
+    }
+
+    #[test]
+    fn load_missing_font() {
+        crate::tests::initialize_test(false);
+        let path = CStr::from_bytes_with_nul(b"S:missing.bin\0").unwrap();
+        assert!(matches!(Font::load(path), Err(LvError::InvalidReference)));
+    }
+}
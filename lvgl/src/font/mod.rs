@@ -18,8 +18,19 @@
 //! }
 //! ```
 //!
-//! Built-in fonts are *only* available if the `nightly` feature is enabled for
-//! the `lvgl` crate.
+//! The `montserrat_*()` constructors are *only* available if the `nightly`
+//! feature is enabled for the `lvgl` crate. On stable, look the size up at
+//! runtime instead, which returns `None` if it wasn't enabled:
+//! ```
+//! use lvgl::font::Font;
+//! use lvgl::style::Style;
+//!
+//! lvgl::init();
+//! let mut my_style = Style::default();
+//! if let Some(font) = Font::montserrat(14) {
+//!     my_style.set_text_font(font);
+//! }
+//! ```
 //!
 //! # Custom fonts
 //! Custom fonts encoded into C files can be added. At compile time, the
@@ -3,7 +3,6 @@ use crate::input_device::InputDriver;
 use crate::widgets::Widget;
 use crate::{Event, LvError, LvResult, Obj};
 use core::ptr::NonNull;
-#[cfg(not(feature = "rust_timer"))]
 use core::time::Duration;
 use core::{ptr, result};
 
@@ -12,6 +11,7 @@ use core::{ptr, result};
 pub enum CoreError {
     ResourceNotAvailable,
     OperationFailed,
+    OutOfMemory,
 }
 
 type Result<T> = result::Result<T, CoreError>;
@@ -26,7 +26,7 @@ pub(crate) fn disp_drv_register<const N: usize>(
         lvgl_sys::lv_disp_drv_register(ptr)
     };
     Ok(Display::from_raw(
-        NonNull::new(disp_ptr).ok_or(CoreError::OperationFailed)?,
+        NonNull::new(disp_ptr).ok_or(CoreError::OutOfMemory)?,
         drop,
     ))
 }
@@ -63,20 +63,27 @@ pub fn tick_inc(tick_period: Duration) {
 }
 
 /// Calls the LVGL timer handler. This function should be called periodically.
+///
+/// Returns the time until the next timer is due, so the caller can sleep
+/// until then. `u32::MAX` milliseconds means no timer is scheduled.
+#[inline]
+pub fn task_handler() -> Duration {
+    crate::check_thread();
+    let next = unsafe { lvgl_sys::lv_timer_handler() };
+    Duration::from_millis(next.into())
+}
+
+/// Like `task_handler()`, ignoring the time until the next timer.
 #[inline]
-pub fn task_handler() {
-    unsafe { lvgl_sys::lv_timer_handler() };
+pub fn task_handler_unit() {
+    task_handler();
 }
 
 /// Directly send an event to a specific widget.
 #[inline]
-pub fn event_send<'a, W: Widget<'a>>(
-    obj: &mut W,
-    event: Event<<W as Widget<'a>>::SpecialEvent>,
-) {
-    unsafe {
-        lvgl_sys::lv_event_send(obj.raw().as_mut(), event.into(), ptr::null_mut());
-    };
+pub fn event_send<'a, W: Widget<'a>>(obj: &mut W, event: Event<<W as Widget<'a>>::SpecialEvent>) {
+    // The object being deleted by a callback is not an error here
+    let _ = obj.send_event(event);
 }
 
 /// Register an input device driver to LVGL.
@@ -90,3 +97,27 @@ pub(crate) fn indev_drv_register<D>(input_device: &mut impl InputDriver<D>) -> L
     };
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::misc::anim::Animation;
+    use crate::widgets::Btn;
+
+    #[test]
+    fn task_handler_returns_time_until_anim() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let mut anim =
+            Animation::new(&mut btn, Duration::from_millis(500), 0, 100, |_, _| {}).unwrap();
+        anim.start();
+
+        crate::tick_inc(Duration::from_millis(10));
+        let next = task_handler();
+        // The animation timer runs every display refresh period
+        let period = Duration::from_millis(lvgl_sys::LV_DISP_DEF_REFR_PERIOD.into());
+        assert!(next <= period, "{:?}", next);
+    }
+}
@@ -10,6 +10,9 @@ pub enum EncoderInputData {
     LongPress,
     TurnLeft,
     TurnRight,
+    /// Steps turned since the last read, positive clockwise. Only taken into
+    /// account while the button is released.
+    Turn(i16),
 }
 
 impl EncoderInputData {
@@ -114,57 +117,29 @@ unsafe extern "C" fn read_input<F>(
     let user_closure = &mut *((*indev_drv).user_data as *mut F);
     // call user data
     let info = user_closure();
+    let (state, continue_reading) = match info {
+        BufferStatus::Once(state) => (state, false),
+        BufferStatus::Buffered(state) => (state, true),
+    };
+    let (d, state) = match state {
+        InputState::Pressed(Data::Encoder(d)) => {
+            (d, lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_PRESSED)
+        }
+        InputState::Released(Data::Encoder(d)) => {
+            (d, lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_RELEASED)
+        }
+        _ => panic!("Non-encoder data returned from encoder device!"),
+    };
     unsafe {
-        (*data).continue_reading = match info {
-            BufferStatus::Once(b) => {
-                (*data).state = match b {
-                    InputState::Pressed(Data::Encoder(d)) => {
-                        (*data).key = match d {
-                            EncoderInputData::Press => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::LongPress => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::TurnLeft => lvgl_sys::LV_KEY_LEFT,
-                            EncoderInputData::TurnRight => lvgl_sys::LV_KEY_RIGHT,
-                        };
-                        lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_PRESSED
-                    }
-                    InputState::Released(Data::Encoder(d)) => {
-                        (*data).key = match d {
-                            EncoderInputData::Press => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::LongPress => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::TurnLeft => lvgl_sys::LV_KEY_LEFT,
-                            EncoderInputData::TurnRight => lvgl_sys::LV_KEY_RIGHT,
-                        };
-                        lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_RELEASED
-                    }
-                    _ => panic!("Non-encoder data returned from encoder device!"),
-                };
-                false
-            }
-            BufferStatus::Buffered(b) => {
-                (*data).state = match b {
-                    InputState::Pressed(Data::Encoder(d)) => {
-                        (*data).key = match d {
-                            EncoderInputData::Press => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::LongPress => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::TurnLeft => lvgl_sys::LV_KEY_LEFT,
-                            EncoderInputData::TurnRight => lvgl_sys::LV_KEY_RIGHT,
-                        };
-                        lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_PRESSED
-                    }
-                    InputState::Released(Data::Encoder(d)) => {
-                        (*data).key = match d {
-                            EncoderInputData::Press => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::LongPress => lvgl_sys::LV_KEY_ENTER,
-                            EncoderInputData::TurnLeft => lvgl_sys::LV_KEY_LEFT,
-                            EncoderInputData::TurnRight => lvgl_sys::LV_KEY_RIGHT,
-                        };
-                        lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_RELEASED
-                    }
-                    _ => panic!("Non-encoder data returned from encoder device!"),
-                };
-                true
-            }
+        match d {
+            EncoderInputData::Press => (*data).key = lvgl_sys::LV_KEY_ENTER,
+            EncoderInputData::LongPress => (*data).key = lvgl_sys::LV_KEY_ENTER,
+            EncoderInputData::TurnLeft => (*data).key = lvgl_sys::LV_KEY_LEFT,
+            EncoderInputData::TurnRight => (*data).key = lvgl_sys::LV_KEY_RIGHT,
+            EncoderInputData::Turn(diff) => (*data).enc_diff = diff,
         }
+        (*data).state = state;
+        (*data).continue_reading = continue_reading;
     }
 }
 
@@ -173,13 +148,17 @@ unsafe extern "C" fn feedback(_indev_drv: *mut lvgl_sys::lv_indev_drv_t, _code:
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Display;
+    use crate::group::Group;
+    use crate::widgets::Btn;
+    use crate::{Display, NativeObject};
+    use core::cell::Cell;
     use core::marker::PhantomData;
     use embedded_graphics::draw_target::DrawTarget;
     use embedded_graphics::geometry::Size;
     use embedded_graphics::pixelcolor::PixelColor;
     use embedded_graphics::prelude::OriginDimensions;
     use embedded_graphics::Pixel;
+    use std::rc::Rc;
 
     struct FakeDisplay<C>
     where
@@ -223,4 +202,39 @@ mod test {
 
         let _encoder = Encoder::register(read_encoder_device, &display).unwrap();
     }
+
+    #[test]
+    fn encoder_turn_moves_focus() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+
+        let diff = Rc::new(Cell::new(0));
+        let read_diff = diff.clone();
+        let mut encoder = Encoder::register(
+            move || EncoderInputData::Turn(read_diff.get()).released().once(),
+            &display,
+        )
+        .unwrap();
+        let mut group = Group::default();
+        let btns = [
+            Btn::create(&mut screen).unwrap(),
+            Btn::create(&mut screen).unwrap(),
+            Btn::create(&mut screen).unwrap(),
+        ];
+        for btn in &btns {
+            group.add_obj(btn).unwrap();
+        }
+        group.set_indev(&mut encoder).unwrap();
+        let focused = || unsafe { lvgl_sys::lv_group_get_focused(group.raw().unwrap().as_ptr()) };
+        assert_eq!(focused(), btns[0].raw().as_ptr());
+
+        diff.set(2);
+        unsafe { lvgl_sys::lv_indev_read_timer_cb(encoder.driver.read_timer) };
+        assert_eq!(focused(), btns[2].raw().as_ptr());
+
+        diff.set(-1);
+        unsafe { lvgl_sys::lv_indev_read_timer_cb(encoder.driver.read_timer) };
+        assert_eq!(focused(), btns[1].raw().as_ptr());
+    }
 }
@@ -1,6 +1,8 @@
 use super::encoder::*;
+use super::keypad::*;
 use super::pointer::*;
-use crate::LvResult;
+use crate::group::Group;
+use crate::{LvError, LvResult};
 
 /// Generic data which can be associated with an input device driver. Varies
 /// based on the concrete type of the input device driver
@@ -10,6 +12,8 @@ pub enum Data {
     Pointer(PointerInputData),
     /// Encoder-specific data.
     Encoder(EncoderInputData),
+    /// Keypad-specific data.
+    Keypad(KeypadInputData),
 }
 
 /// Boolean states for an input.
@@ -69,6 +73,14 @@ pub trait InputDriver<D> {
         display: &crate::Display,
     ) -> LvResult<D>;
 
+    /// Sends the input of the device to the focused object of `group`. This
+    /// is needed for keypads and encoders, which don't point at objects.
+    fn set_group(&mut self, group: &Group) -> LvResult<()> {
+        let dsc = self.get_descriptor().ok_or(LvError::InvalidReference)?;
+        unsafe { lvgl_sys::lv_indev_set_group(dsc, group.raw()?.as_ptr()) }
+        Ok(())
+    }
+
     /// Sets the descriptor for the input driver wrapper.
     ///
     /// # Safety
@@ -0,0 +1,171 @@
+use super::{BufferStatus, Data, InputDriver, InputState};
+use crate::Box;
+use crate::{LvError, LvResult};
+use core::mem::MaybeUninit;
+
+/// Keypad-specific input data. Contains the key, either one of the
+/// `lvgl_sys::LV_KEY_*` control keys or a Unicode character.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct KeypadInputData {
+    pub key: u32,
+}
+
+impl KeypadInputData {
+    pub fn new(key: u32) -> Self {
+        Self { key }
+    }
+
+    pub fn pressed(self) -> InputState {
+        InputState::Pressed(Data::Keypad(self))
+    }
+
+    pub fn released(self) -> InputState {
+        InputState::Released(Data::Keypad(self))
+    }
+}
+
+/// Represents a keypad-type input driver. Keys are sent to the focused
+/// object of the `Group` the keypad is associated with.
+pub struct Keypad {
+    pub(crate) driver: Box<lvgl_sys::lv_indev_drv_t>,
+    pub(crate) descriptor: Option<*mut lvgl_sys::lv_indev_t>,
+}
+
+impl InputDriver<Keypad> for Keypad {
+    fn register<F>(handler: F, _: &crate::Display) -> LvResult<Self>
+    where
+        F: Fn() -> BufferStatus,
+    {
+        let driver = unsafe {
+            let mut indev_drv = MaybeUninit::uninit();
+            lvgl_sys::lv_indev_drv_init(indev_drv.as_mut_ptr());
+            let mut indev_drv = Box::new(indev_drv.assume_init());
+            indev_drv.type_ = lvgl_sys::lv_indev_type_t_LV_INDEV_TYPE_KEYPAD;
+            indev_drv.read_cb = Some(read_input::<F>);
+            indev_drv.feedback_cb = Some(feedback);
+            indev_drv.user_data = Box::into_raw(Box::new(handler)) as *mut _;
+            indev_drv
+        };
+
+        let mut dev = Self {
+            driver,
+            descriptor: None,
+        };
+
+        match crate::indev_drv_register(&mut dev) {
+            Ok(()) => Ok(dev),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn get_driver(&mut self) -> &mut lvgl_sys::lv_indev_drv_t {
+        self.driver.as_mut()
+    }
+
+    fn get_descriptor(&mut self) -> Option<&mut lvgl_sys::lv_indev_t> {
+        match self.descriptor {
+            Some(d) => unsafe { d.as_mut() },
+            None => None,
+        }
+    }
+
+    unsafe fn new_raw(
+        read_cb: Option<
+            unsafe extern "C" fn(*mut lvgl_sys::lv_indev_drv_t, *mut lvgl_sys::lv_indev_data_t),
+        >,
+        feedback_cb: Option<unsafe extern "C" fn(*mut lvgl_sys::lv_indev_drv_t, u8)>,
+        _: &crate::Display,
+    ) -> LvResult<Self> {
+        let driver = unsafe {
+            let mut indev_drv = MaybeUninit::uninit();
+            lvgl_sys::lv_indev_drv_init(indev_drv.as_mut_ptr());
+            let mut indev_drv = Box::new(indev_drv.assume_init());
+            indev_drv.type_ = lvgl_sys::lv_indev_type_t_LV_INDEV_TYPE_KEYPAD;
+            indev_drv.read_cb = read_cb;
+            indev_drv.feedback_cb = feedback_cb;
+            indev_drv
+        };
+
+        let mut dev = Self {
+            driver,
+            descriptor: None,
+        };
+
+        match crate::indev_drv_register(&mut dev) {
+            Ok(()) => Ok(dev),
+            Err(e) => Err(e),
+        }
+    }
+
+    unsafe fn set_descriptor(&mut self, descriptor: *mut lvgl_sys::lv_indev_t) -> LvResult<()> {
+        if self.descriptor.is_none() {
+            self.descriptor = Some(descriptor);
+        } else {
+            return Err(LvError::AlreadyInUse);
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn read_input<F>(
+    indev_drv: *mut lvgl_sys::lv_indev_drv_t,
+    data: *mut lvgl_sys::lv_indev_data_t,
+) where
+    F: Fn() -> BufferStatus,
+{
+    // convert user data to function
+    let user_closure = &mut *((*indev_drv).user_data as *mut F);
+    // call user data
+    let info = user_closure();
+    let (state, continue_reading) = match info {
+        BufferStatus::Once(state) => (state, false),
+        BufferStatus::Buffered(state) => (state, true),
+    };
+    unsafe {
+        (*data).state = match state {
+            InputState::Pressed(Data::Keypad(d)) => {
+                (*data).key = d.key;
+                lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_PRESSED
+            }
+            InputState::Released(Data::Keypad(d)) => {
+                (*data).key = d.key;
+                lvgl_sys::lv_indev_state_t_LV_INDEV_STATE_RELEASED
+            }
+            _ => panic!("Non-keypad data returned from keypad device!"),
+        };
+        (*data).continue_reading = continue_reading;
+    }
+}
+
+unsafe extern "C" fn feedback(_indev_drv: *mut lvgl_sys::lv_indev_drv_t, _code: u8) {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input_device::Group;
+    use crate::widgets::Btn;
+    use crate::Display;
+
+    #[test]
+    fn keypad_input_device() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+
+        fn read_keypad_device() -> BufferStatus {
+            KeypadInputData::new(lvgl_sys::LV_KEY_ENTER)
+                .pressed()
+                .once()
+        }
+
+        let mut keypad = Keypad::register(read_keypad_device, &display).unwrap();
+        let mut group = Group::default();
+        let btn = Btn::create(&mut screen).unwrap();
+        group.add_obj(&btn).unwrap();
+        keypad.set_group(&group).unwrap();
+
+        unsafe { lvgl_sys::lv_indev_read_timer_cb(keypad.driver.read_timer) };
+        let key = unsafe { lvgl_sys::lv_indev_get_key(keypad.descriptor.unwrap()) };
+        assert_eq!(key, lvgl_sys::LV_KEY_ENTER);
+    }
+}
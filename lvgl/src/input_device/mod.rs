@@ -3,9 +3,9 @@
 //! LVGL supports 4 types of input device. The current status as to support in
 //! this library is:
 //! - Pointer: Fully supported
-//! - Keyboard: Unsupported
+//! - Keyboard: Supported, see `keypad::Keypad`
 //! - Button: Unsupported
-//! - Encoder: Unsupported
+//! - Encoder: Partially supported, see `encoder::Encoder`
 //!
 //! The general order of operations when creating an input device is
 //! initializing an instance of the desired device, setting a callback function
@@ -30,7 +30,9 @@
 //! For a full example, see the `button_click` example.
 
 mod generic;
+pub use crate::group::Group;
 pub use generic::*;
 
 pub mod encoder;
+pub mod keypad;
 pub mod pointer;
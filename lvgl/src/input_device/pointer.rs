@@ -12,6 +12,28 @@ pub enum PointerInputData {
 }
 
 impl PointerInputData {
+    /// Transforms the touched point, e.g. to undo the scaling of a simulator
+    /// window. Rotation needs no handling here, as LVGL maps points to the
+    /// rotation of the display itself.
+    ///
+    /// ```
+    /// use embedded_graphics::prelude::Point;
+    /// use lvgl::input_device::pointer::PointerInputData;
+    ///
+    /// let data = PointerInputData::Touch(Point::new(80, 40))
+    ///     .map(|p| Point::new(p.x / 2, p.y / 2));
+    /// assert_eq!(data, PointerInputData::Touch(Point::new(40, 20)));
+    /// ```
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Point) -> Point,
+    {
+        match self {
+            PointerInputData::Touch(point) => PointerInputData::Touch(f(point)),
+            key => key,
+        }
+    }
+
     pub fn pressed(self) -> InputState {
         InputState::Pressed(Data::Pointer(self))
     }
@@ -27,6 +49,38 @@ pub struct Pointer {
     pub(crate) descriptor: Option<*mut lvgl_sys::lv_indev_t>,
 }
 
+impl Pointer {
+    /// Like `register()`, but the handler may mutate what it captures, e.g.
+    /// to drain a queue of touch events. The handler is moved into LVGL
+    /// memory and lives as long as the input device stays registered, so it
+    /// can't borrow anything.
+    pub fn register_mut<F>(handler: F, _: &crate::Display) -> LvResult<Self>
+    where
+        F: FnMut() -> BufferStatus + 'static,
+    {
+        let driver = unsafe {
+            let mut indev_drv = MaybeUninit::uninit();
+            lvgl_sys::lv_indev_drv_init(indev_drv.as_mut_ptr());
+            let mut indev_drv = Box::new(indev_drv.assume_init());
+            indev_drv.type_ = lvgl_sys::lv_indev_type_t_LV_INDEV_TYPE_POINTER;
+            indev_drv.read_cb = Some(read_input::<F>);
+            indev_drv.feedback_cb = Some(feedback);
+            indev_drv.user_data = Box::into_raw(Box::new(handler)) as *mut _;
+            indev_drv
+        };
+
+        let mut dev = Self {
+            driver,
+            descriptor: None,
+        };
+
+        match crate::indev_drv_register(&mut dev) {
+            Ok(()) => Ok(dev),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 impl InputDriver<Pointer> for Pointer {
     fn register<F>(handler: F, _: &crate::Display) -> LvResult<Self>
     where
@@ -107,7 +161,7 @@ unsafe extern "C" fn read_input<F>(
     indev_drv: *mut lvgl_sys::lv_indev_drv_t,
     data: *mut lvgl_sys::lv_indev_data_t,
 ) where
-    F: Fn() -> BufferStatus,
+    F: FnMut() -> BufferStatus,
 {
     // convert user data to function
     let user_closure = &mut *((*indev_drv).user_data as *mut F);
@@ -178,7 +232,7 @@ unsafe extern "C" fn feedback(_indev_drv: *mut lvgl_sys::lv_indev_drv_t, _code:
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Display;
+    use crate::{Display, DisplayRotation, DrawBuffer};
     use core::marker::PhantomData;
     use embedded_graphics::draw_target::DrawTarget;
     use embedded_graphics::geometry::Size;
@@ -230,4 +284,51 @@ mod test {
 
         let _touch_screen = Pointer::register(read_touchpad_device, &display).unwrap();
     }
+
+    #[test]
+    fn pointer_drains_queued_points() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+
+        let mut queue = vec![Point::new(10, 20), Point::new(30, 40)];
+        let pointer = Pointer::register_mut(
+            move || match queue.pop() {
+                Some(point) => PointerInputData::Touch(point).pressed().once(),
+                None => PointerInputData::Touch(Point::new(0, 0)).released().once(),
+            },
+            &display,
+        )
+        .unwrap();
+
+        let mut point = MaybeUninit::uninit();
+        for expected in [(30, 40), (10, 20)] {
+            unsafe {
+                lvgl_sys::lv_indev_read_timer_cb(pointer.driver.read_timer);
+                lvgl_sys::lv_indev_get_point(pointer.descriptor.unwrap(), point.as_mut_ptr());
+            }
+            let read = unsafe { point.assume_init() };
+            assert_eq!((read.x, read.y), expected);
+        }
+    }
+
+    #[test]
+    fn pointer_follows_display_rotation() {
+        crate::tests::initialize_test(false);
+        let buffer = DrawBuffer::<{ 320 * 24 }>::default();
+        let display = Display::register(buffer, 320, 240, |_| {}).unwrap();
+        display.set_rotation(DisplayRotation::Rot90);
+
+        fn read_touchpad_device() -> BufferStatus {
+            PointerInputData::Touch(Point::new(10, 20)).pressed().once()
+        }
+        let pointer = Pointer::register(read_touchpad_device, &display).unwrap();
+
+        let mut point = MaybeUninit::uninit();
+        let point = unsafe {
+            lvgl_sys::lv_indev_read_timer_cb(pointer.driver.read_timer);
+            lvgl_sys::lv_indev_get_point(pointer.descriptor.unwrap(), point.as_mut_ptr());
+            point.assume_init()
+        };
+        assert_eq!((point.x, point.y), (240 - 20 - 1, 10));
+    }
 }
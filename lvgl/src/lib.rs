@@ -18,9 +18,18 @@
 
 pub use lvgl_sys as sys;
 
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(all(feature = "std", debug_assertions))]
+use std::sync::{Mutex, PoisonError};
+#[cfg(all(feature = "std", debug_assertions))]
+use std::thread::{self, ThreadId};
+
 #[macro_use]
 extern crate bitflags;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[macro_use]
 mod lv_core;
 
@@ -74,22 +83,65 @@ pub mod widgets;
 #[cfg(feature = "rust_timer")]
 pub mod timer;
 
-#[cfg(feature = "unsafe_no_autoinit")]
-static mut IS_INIT: bool = false;
-#[cfg(not(feature = "unsafe_no_autoinit"))]
-static mut IS_INIT: bool = true;
+static IS_INIT: AtomicBool = AtomicBool::new(cfg!(not(feature = "unsafe_no_autoinit")));
+
+// The thread LVGL was initialized on or first used from, the only one
+// allowed to use it
+#[cfg(all(feature = "std", debug_assertions))]
+static OWNER: Mutex<Option<ThreadId>> = Mutex::new(None);
+
+/// Makes the current thread the only one allowed to use LVGL, or with `false`
+/// lets the next thread using it claim it.
+fn claim_thread(claim: bool) {
+    #[cfg(all(feature = "std", debug_assertions))]
+    {
+        let owner = claim.then(|| thread::current().id());
+        *OWNER.lock().unwrap_or_else(PoisonError::into_inner) = owner;
+    }
+    #[cfg(not(all(feature = "std", debug_assertions)))]
+    let _ = claim;
+}
+
+/// Panics if LVGL is used from another thread than the one it was
+/// initialized on. If no thread claimed LVGL yet, e.g. after the automatic
+/// initialization, the current one does. Only checks in debug builds with
+/// the `std` feature.
+#[inline]
+pub(crate) fn check_thread() {
+    #[cfg(all(feature = "std", debug_assertions))]
+    {
+        let current = thread::current().id();
+        let owner = *OWNER
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_or_insert(current);
+        assert!(
+            owner == current,
+            "LVGL used from another thread than the one it was initialized on"
+        );
+    }
+}
 
 /// Initializes LVGL. Call at the start of the program, or after safely
-/// deinitializing with `deinit()`.
+/// deinitializing with `deinit()`. Calling it again does nothing.
+///
+/// LVGL is not thread-safe, so it must only be used from the thread calling
+/// this, or with the automatic initialization from the first thread using
+/// it. With the `std` feature, debug builds panic otherwise.
 pub fn init() {
-    unsafe {
-        if !IS_INIT {
-            lvgl_sys::lv_init();
-            IS_INIT = true;
-        }
+    if !IS_INIT.load(Ordering::Acquire) {
+        unsafe { lvgl_sys::lv_init() };
+        claim_thread(true);
+        IS_INIT.store(true, Ordering::Release);
     }
 }
 
+/// Returns whether LVGL is initialized. Creating displays and widgets fails
+/// with `LvError::Uninitialized` until it is.
+pub fn is_init() -> bool {
+    IS_INIT.load(Ordering::Acquire)
+}
+
 /// Uninitializes LVGL. Make sure to reinitialize LVGL with `init()` before
 /// accessing its functionality
 ///
@@ -99,11 +151,10 @@ pub fn init() {
 /// LVGL is reinitialized.
 #[cfg(not(feature = "custom_allocator"))]
 pub unsafe fn deinit() {
-    unsafe {
-        if IS_INIT {
-            lvgl_sys::lv_deinit();
-            IS_INIT = false;
-        }
+    if IS_INIT.load(Ordering::Acquire) {
+        unsafe { lvgl_sys::lv_deinit() };
+        claim_thread(false);
+        IS_INIT.store(false, Ordering::Release);
     }
 }
 
@@ -113,6 +164,7 @@ fn once_init() {
     unsafe {
         lvgl_sys::lv_init();
     }
+    // This runs before `main()`, which may hand LVGL to another thread
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use crate::input_device::InputDriver;
-use crate::{LvError, LvResult, NativeObject};
+use crate::widgets::Widget;
+use crate::{LvError, LvResult, NativeObject, Obj};
 use core::ptr::NonNull;
 
 /// A group of objects, for use with `Encoder` and `Keypad`-type input devices.
@@ -31,6 +32,31 @@ impl Group {
         Ok(())
     }
 
+    /// Moves the focus to the next object of the group.
+    pub fn focus_next(&mut self) -> LvResult<()> {
+        unsafe { lvgl_sys::lv_group_focus_next(self.raw()?.as_mut()) }
+        Ok(())
+    }
+
+    /// Moves the focus to the previous object of the group.
+    pub fn focus_prev(&mut self) -> LvResult<()> {
+        unsafe { lvgl_sys::lv_group_focus_prev(self.raw()?.as_mut()) }
+        Ok(())
+    }
+
+    /// Returns the focused object, if any.
+    pub fn focused(&self) -> Option<Obj<'_>> {
+        let obj = unsafe { lvgl_sys::lv_group_get_focused(self.raw().ok()?.as_ptr()) };
+        unsafe { Obj::from_raw(NonNull::new(obj)?) }
+    }
+
+    /// Sets whether the focused object is being edited, in which case
+    /// encoder turns adjust its value instead of moving the focus.
+    pub fn set_editing(&mut self, editing: bool) -> LvResult<()> {
+        unsafe { lvgl_sys::lv_group_set_editing(self.raw()?.as_mut(), editing) }
+        Ok(())
+    }
+
     /// Associates an input device to the group.
     pub fn set_indev<D>(&mut self, indev: &mut impl InputDriver<D>) -> LvResult<()> {
         let dsc = match indev.get_descriptor() {
@@ -72,4 +98,22 @@ mod test {
         let btn = Btn::create(&mut screen).unwrap();
         group.add_obj(&btn).unwrap();
     }
+
+    #[test]
+    fn group_focus_next() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut group = Group::default();
+        let first = Btn::create(&mut screen).unwrap();
+        let second = Btn::create(&mut screen).unwrap();
+        group.add_obj(&first).unwrap();
+        group.add_obj(&second).unwrap();
+        assert_eq!(group.focused().unwrap().raw(), first.raw());
+
+        group.focus_next().unwrap();
+        assert_eq!(group.focused().unwrap().raw(), second.raw());
+        group.focus_prev().unwrap();
+        assert_eq!(group.focused().unwrap().raw(), first.raw());
+    }
 }
@@ -0,0 +1,98 @@
+// The style properties `NativeObject` has typed `set_style_*()` setters for.
+// Also included by `build.rs`, which hides the generated setters for them.
+macro_rules! with_local_style_props {
+    ($callback:ident) => {
+        $callback! {
+            align: Align,
+            arc_color: Color,
+            arc_opa: Opacity,
+            arc_rounded: bool,
+            arc_width: i16,
+            base_dir: BaseDir,
+            bg_color: Color,
+            bg_dither_mode: DitherMode,
+            bg_grad_color: Color,
+            bg_grad_dir: GradDir,
+            bg_grad_stop: i16,
+            bg_img_opa: Opacity,
+            bg_img_recolor: Color,
+            bg_img_recolor_opa: Opacity,
+            bg_img_src: ImageSrc,
+            bg_img_tiled: bool,
+            bg_main_stop: i16,
+            bg_opa: Opacity,
+            blend_mode: BlendMode,
+            border_color: Color,
+            border_opa: Opacity,
+            border_post: bool,
+            border_side: BorderSide,
+            border_width: i16,
+            clip_corner: bool,
+            color_filter_opa: Opacity,
+            flex_flow: FlexFlow,
+            flex_grow: u8,
+            flex_main_place: FlexAlign,
+            flex_cross_place: FlexAlign,
+            flex_track_place: FlexAlign,
+            grid_cell_column_pos: i16,
+            grid_cell_column_span: i16,
+            grid_cell_row_pos: i16,
+            grid_cell_row_span: i16,
+            grid_cell_x_align: GridAlign,
+            grid_cell_y_align: GridAlign,
+            grid_column_align: GridAlign,
+            grid_row_align: GridAlign,
+            height: i16,
+            img_opa: Opacity,
+            img_recolor: Color,
+            img_recolor_opa: Opacity,
+            layout: Layout,
+            line_color: Color,
+            line_dash_gap: i16,
+            line_dash_width: i16,
+            line_opa: Opacity,
+            line_rounded: bool,
+            line_width: i16,
+            max_height: i16,
+            max_width: i16,
+            min_height: i16,
+            min_width: i16,
+            opa: Opacity,
+            outline_color: Color,
+            outline_opa: Opacity,
+            outline_pad: i16,
+            outline_width: i16,
+            pad_bottom: i16,
+            pad_column: i16,
+            pad_left: i16,
+            pad_right: i16,
+            pad_row: i16,
+            pad_top: i16,
+            radius: i16,
+            shadow_color: Color,
+            shadow_ofs_x: i16,
+            shadow_ofs_y: i16,
+            shadow_opa: Opacity,
+            shadow_spread: i16,
+            shadow_width: i16,
+            text_align: TextAlign,
+            text_color: Color,
+            text_decor: TextDecor,
+            text_font: Font,
+            text_letter_space: i16,
+            text_line_space: i16,
+            text_opa: Opacity,
+            transform_angle: i16,
+            transform_height: i16,
+            transform_pivot_x: i16,
+            transform_pivot_y: i16,
+            transform_width: i16,
+            transform_zoom: i16,
+            translate_x: i16,
+            translate_y: i16,
+            width: i16,
+            x: i16,
+            y: i16,
+        }
+    };
+}
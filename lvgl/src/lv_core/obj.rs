@@ -5,16 +5,251 @@
 //! are special in that they do not have a parent object but do still implement
 //! `NativeObject`.
 
+use crate::font::Font;
+use crate::misc::area::Size;
+use crate::style::{
+    BaseDir, BlendMode, BorderSide, CoordDesc, DitherMode, FlexAlign, FlexFlow, GradDir, GridAlign,
+    ImageSrc, Layout, Opacity, Style, TextDecor,
+};
 use crate::widgets::Widget;
-use crate::{LvError, LvResult};
+use crate::{Align, Color, Event, LvError, LvResult, TextAlign};
 use core::fmt::{self, Debug};
 use core::marker::PhantomData;
+use core::ops::BitOr;
 use core::ptr::{self, NonNull};
+use paste::paste;
+
+macro_rules! gen_lv_obj_style {
+    ($func_name:ident,$vty:ty) => {
+        paste! {
+            #[inline]
+            fn [<set_style_ $func_name>](&mut self, value: $vty, selector: impl Into<Selector>) {
+                unsafe {
+                    lvgl_sys::[<lv_obj_set_style_ $func_name>](
+                        self.raw().as_mut(),
+                        value.into(),
+                        selector.into().into(),
+                    );
+                }
+            }
+        }
+    };
+}
+
+macro_rules! gen_lv_obj_styles {
+    ($($func_name:ident: $vty:ty),* $(,)?) => {
+        $(gen_lv_obj_style!($func_name, $vty);)*
+    };
+}
+
+include!("local_style_props.rs");
 
 /// Represents a native LVGL object.
 pub trait NativeObject {
     /// Provide common way to access to the underlying native object pointer.
     fn raw(&self) -> NonNull<lvgl_sys::lv_obj_t>;
+
+    /// Sends an event to the object, calling its event callbacks. Fails if
+    /// a callback deleted the object.
+    fn send_event<S>(&mut self, event: Event<S>) -> LvResult<()> {
+        send_event_raw(self.raw(), event.into(), ptr::null_mut())
+    }
+
+    /// Sets the given flags on the object.
+    fn add_flag(&mut self, flag: ObjFlag) {
+        unsafe { lvgl_sys::lv_obj_add_flag(self.raw().as_mut(), flag.into()) }
+    }
+
+    /// Clears the given flags on the object.
+    fn clear_flag(&mut self, flag: ObjFlag) {
+        unsafe { lvgl_sys::lv_obj_clear_flag(self.raw().as_mut(), flag.into()) }
+    }
+
+    /// Returns whether all the given flags are set on the object.
+    fn has_flag(&self, flag: ObjFlag) -> bool {
+        unsafe { lvgl_sys::lv_obj_has_flag(self.raw().as_ptr(), flag.into()) }
+    }
+
+    /// Sets the given states on the object.
+    fn add_state(&mut self, state: ObjState) {
+        unsafe { lvgl_sys::lv_obj_add_state(self.raw().as_mut(), state.into()) }
+    }
+
+    /// Clears the given states on the object.
+    fn clear_state(&mut self, state: ObjState) {
+        unsafe { lvgl_sys::lv_obj_clear_state(self.raw().as_mut(), state.into()) }
+    }
+
+    /// Returns whether the object is in all the given states.
+    fn has_state(&self, state: ObjState) -> bool {
+        unsafe { lvgl_sys::lv_obj_has_state(self.raw().as_ptr(), state.into()) }
+    }
+
+    /// Returns the child at `idx`, in the order the children were created,
+    /// or `None` if there are not as many children.
+    fn child(&self, idx: u32) -> Option<Obj<'_>> {
+        let idx = i32::try_from(idx).ok()?;
+        let raw = unsafe { lvgl_sys::lv_obj_get_child(self.raw().as_ptr(), idx) };
+        NonNull::new(raw).and_then(|raw| unsafe { Obj::from_raw(raw) })
+    }
+
+    /// Returns the number of children of the object.
+    fn child_count(&self) -> u32 {
+        unsafe { lvgl_sys::lv_obj_get_child_cnt(self.raw().as_ptr()) }
+    }
+
+    /// Returns the index of the object among its parent's children, or 0
+    /// for screens.
+    fn index(&self) -> u32 {
+        unsafe { lvgl_sys::lv_obj_get_index(self.raw().as_ptr()) }
+    }
+
+    /// Deletes the object along with all of its children.
+    ///
+    /// Other wrappers of the object or of its children, such as the ones
+    /// returned by `child()`, keep pointing to the freed memory: using them
+    /// afterwards is undefined behaviour. Debug builds check that the object
+    /// was not deleted already.
+    fn delete(self)
+    where
+        Self: Sized,
+    {
+        let mut raw = self.raw();
+        unsafe {
+            debug_assert!(lvgl_sys::lv_obj_is_valid(raw.as_ptr()));
+            lvgl_sys::lv_obj_del(raw.as_mut());
+        }
+    }
+
+    /// Deletes all children of the object. As with `delete()`, wrappers of
+    /// the children must not be used afterwards.
+    fn clean(&mut self) {
+        unsafe {
+            debug_assert!(lvgl_sys::lv_obj_is_valid(self.raw().as_ptr()));
+            lvgl_sys::lv_obj_clean(self.raw().as_mut());
+        }
+    }
+
+    /// Adds `style` to a part of the object, either in every state when
+    /// given a `Part` or only in some states with e.g.
+    /// `Part::Main | ObjState::PRESSED`. Styles added later take precedence
+    /// over earlier ones. LVGL keeps using the style until the object is
+    /// deleted, so it has to be `leak()`ed first.
+    fn add_style(&mut self, selector: impl Into<Selector>, style: &'static Style) {
+        // LVGL only reads the style, the pointer is mutable for historical reasons
+        unsafe {
+            lvgl_sys::lv_obj_add_style(
+                self.raw().as_mut(),
+                &*style.raw as *const _ as *mut _,
+                selector.into().into(),
+            )
+        }
+    }
+
+    /// Removes `style` from the object, where it was added with the same
+    /// selector.
+    fn remove_style(&mut self, selector: impl Into<Selector>, style: &Style) {
+        unsafe {
+            lvgl_sys::lv_obj_remove_style(
+                self.raw().as_mut(),
+                &*style.raw as *const _ as *mut _,
+                selector.into().into(),
+            )
+        }
+    }
+
+    /// Sets the object's width and height.
+    fn set_size(&mut self, w: Size, h: Size) {
+        unsafe { lvgl_sys::lv_obj_set_size(self.raw().as_mut(), w.into(), h.into()) }
+    }
+
+    /// Sets the object's width. Alternatively, use `set_size()`.
+    fn set_width(&mut self, w: Size) {
+        unsafe { lvgl_sys::lv_obj_set_width(self.raw().as_mut(), w.into()) }
+    }
+
+    /// Sets the object's height. Alternatively, use `set_size()`.
+    fn set_height(&mut self, h: Size) {
+        unsafe { lvgl_sys::lv_obj_set_height(self.raw().as_mut(), h.into()) }
+    }
+
+    /// Places the object relative to its parent, then moves it by `x` and
+    /// `y`.
+    fn align(&mut self, align: Align, x: i16, y: i16) {
+        unsafe { lvgl_sys::lv_obj_align(self.raw().as_mut(), align.into(), x, y) }
+    }
+
+    /// Places the object relative to `base`, then moves it by `x` and `y`.
+    /// With the `Out*` alignments the object ends up next to `base`.
+    fn align_to(&mut self, base: &impl NativeObject, align: Align, x: i16, y: i16) {
+        unsafe {
+            lvgl_sys::lv_obj_align_to(self.raw().as_mut(), base.raw().as_ptr(), align.into(), x, y)
+        }
+    }
+
+    /// Places the object in a cell of its parent's grid layout, spanning
+    /// `col_span` columns and `row_span` rows. `col` and `row` align the
+    /// object within the cell.
+    fn set_grid_cell(
+        &mut self,
+        col: GridAlign,
+        col_pos: u8,
+        col_span: u8,
+        row: GridAlign,
+        row_pos: u8,
+        row_span: u8,
+    ) {
+        unsafe {
+            lvgl_sys::lv_obj_set_grid_cell(
+                self.raw().as_mut(),
+                col.into(),
+                col_pos,
+                col_span,
+                row.into(),
+                row_pos,
+                row_span,
+            );
+        }
+    }
+
+    /// Gives the object a grid layout with the given column and row sizes.
+    /// The descriptors must be created with `is_grid` set; LVGL keeps
+    /// pointing to them, see `CoordDesc::leak()`.
+    fn set_grid_dsc<const N: usize, const M: usize>(
+        &mut self,
+        cols: &'static CoordDesc<N>,
+        rows: &'static CoordDesc<M>,
+    ) {
+        unsafe {
+            lvgl_sys::lv_obj_set_grid_dsc_array(self.raw().as_mut(), cols.into(), rows.into());
+        }
+    }
+
+    /// Gives the object a flex layout, placing its children as `flow` says.
+    fn set_flex_flow(&mut self, flow: FlexFlow) {
+        unsafe { lvgl_sys::lv_obj_set_flex_flow(self.raw().as_mut(), flow.into()) }
+    }
+
+    /// Like `send_event()`, passing `param` along with the event. Callbacks
+    /// can read it with `EventContext::get_param()`.
+    fn send_event_with_param<S, T>(&mut self, event: Event<S>, param: &mut T) -> LvResult<()> {
+        send_event_raw(self.raw(), event.into(), param as *mut T as *mut _)
+    }
+
+    // Local styles, applied to the object alone without creating a `Style`.
+    // See the `Style` setters of the same name for what they do.
+    with_local_style_props!(gen_lv_obj_styles);
+}
+
+fn send_event_raw(
+    mut obj: NonNull<lvgl_sys::lv_obj_t>,
+    code: lvgl_sys::lv_event_code_t,
+    param: *mut cty::c_void,
+) -> LvResult<()> {
+    match unsafe { lvgl_sys::lv_event_send(obj.as_mut(), code, param) } as u32 {
+        lvgl_sys::LV_RES_OK => Ok(()),
+        _ => Err(LvError::InvalidReference),
+    }
 }
 
 /// Generic LVGL object.
@@ -41,6 +276,9 @@ impl Debug for Obj<'_> {
 // terms of Obj
 impl<'a> Obj<'a> {
     pub fn create(parent: &'a mut impl NativeObject) -> LvResult<Self> {
+        if !crate::is_init() {
+            return Err(LvError::Uninitialized);
+        }
         unsafe {
             let ptr = lvgl_sys::lv_obj_create(parent.raw().as_mut());
             if let Some(nn_ptr) = ptr::NonNull::new(ptr) {
@@ -50,17 +288,38 @@ impl<'a> Obj<'a> {
                     dependents: PhantomData::<&'a _>,
                 })
             } else {
-                Err(LvError::InvalidReference)
+                Err(LvError::LvOOMemory)
             }
         }
     }
 
     pub fn new() -> crate::LvResult<Self> {
-        let mut parent = crate::display::get_scr_act()?;
+        let mut parent = crate::display::get_scr_act(None)?;
         Self::create(unsafe { &mut *(&mut parent as *mut _) })
     }
 
+    /// Returns the underlying LVGL object, to call LVGL functions that have
+    /// no wrapper yet.
+    pub fn as_raw_ptr(&self) -> *mut lvgl_sys::lv_obj_t {
+        self.raw.as_ptr()
+    }
+
+    /// Wraps an LVGL object, or returns `None` if `ptr` is null.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid object, which outlives the wrapper.
+    pub unsafe fn from_raw_ptr(ptr: *mut lvgl_sys::lv_obj_t) -> Option<Self> {
+        NonNull::new(ptr).map(|raw| Self {
+            raw,
+            dependents: PhantomData,
+        })
+    }
+
     pub fn blank() -> LvResult<Self> {
+        if !crate::is_init() {
+            return Err(LvError::Uninitialized);
+        }
         match NonNull::new(unsafe { lvgl_sys::lv_obj_create(ptr::null_mut()) }) {
             Some(raw) => Ok(Self {
                 raw,
@@ -73,6 +332,7 @@ impl<'a> Obj<'a> {
 
 impl NativeObject for Obj<'_> {
     fn raw(&self) -> ptr::NonNull<lvgl_sys::lv_obj_t> {
+        crate::check_thread();
         self.raw
     }
 }
@@ -183,24 +443,82 @@ macro_rules! define_object {
         }
 
         impl<'a> $item<'a> {
-            pub fn on_event<F>(&mut self, f: F) -> $crate::LvResult<()>
+            /// Returns the underlying LVGL object, to call LVGL functions
+            /// that have no wrapper yet.
+            pub fn as_raw_ptr(&self) -> *mut lvgl_sys::lv_obj_t {
+                self.core.raw().as_ptr()
+            }
+
+            /// Wraps an LVGL object, or returns `None` if `ptr` is null.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must point to a valid object of this widget's class,
+            /// which outlives the wrapper.
+            pub unsafe fn from_raw_ptr(ptr: *mut lvgl_sys::lv_obj_t) -> Option<Self> {
+                core::ptr::NonNull::new(ptr).and_then(|raw| <Self as Widget<'a>>::from_raw(raw))
+            }
+
+            /// Calls `f` on every event the object receives. An object can
+            /// have several callbacks, each called in the order they were
+            /// registered. The closure is freed once the object is deleted.
+            pub fn on_event<F>(&mut self, mut f: F) -> $crate::LvResult<$crate::EventHandle>
             where
-                F: FnMut(Self, $crate::support::Event<<Self as Widget<'a>>::SpecialEvent>),
+                F: FnMut(Self, $crate::support::Event<<Self as Widget<'a>>::SpecialEvent>)
+                    + 'static,
             {
                 use $crate::NativeObject;
                 unsafe {
-                    let obj = self.raw().as_mut();
-                    obj.user_data = $crate::Box::into_raw($crate::Box::new(f)) as *mut _;
-                    lvgl_sys::lv_obj_add_event_cb(
-                        obj,
-                        lvgl_sys::lv_event_cb_t::Some(
-                            $crate::support::event_callback::<'a, Self, F>,
-                        ),
+                    $crate::support::add_event_cb::<Self, _>(
+                        self.raw().as_mut(),
                         lvgl_sys::lv_event_code_t_LV_EVENT_ALL,
-                        obj.user_data,
-                    );
+                        move |obj, event, _: &$crate::support::EventContext| f(obj, event),
+                    )
+                }
+            }
+
+            /// Like `on_event()`, but only calls `f` for events of the given
+            /// kind. LVGL does the filtering, so other events cost nothing.
+            pub fn on_event_filtered<F>(
+                &mut self,
+                code: $crate::support::Event<<Self as Widget<'a>>::SpecialEvent>,
+                mut f: F,
+            ) -> $crate::LvResult<$crate::EventHandle>
+            where
+                F: FnMut(Self, $crate::support::Event<<Self as Widget<'a>>::SpecialEvent>)
+                    + 'static,
+            {
+                use $crate::NativeObject;
+                unsafe {
+                    $crate::support::add_event_cb::<Self, _>(
+                        self.raw().as_mut(),
+                        code.into(),
+                        move |obj, event, _: &$crate::support::EventContext| f(obj, event),
+                    )
+                }
+            }
+
+            /// Like `on_event()`, also giving `f` the details of the event,
+            /// such as the key pressed or the child it originated from.
+            pub fn on_event_with_context<F>(
+                &mut self,
+                f: F,
+            ) -> $crate::LvResult<$crate::EventHandle>
+            where
+                F: FnMut(
+                        Self,
+                        $crate::support::Event<<Self as Widget<'a>>::SpecialEvent>,
+                        &$crate::support::EventContext,
+                    ) + 'static,
+            {
+                use $crate::NativeObject;
+                unsafe {
+                    $crate::support::add_event_cb::<Self, _>(
+                        self.raw().as_mut(),
+                        lvgl_sys::lv_event_code_t_LV_EVENT_ALL,
+                        f,
+                    )
                 }
-                Ok(())
             }
         }
 
@@ -292,3 +610,496 @@ impl From<Part> for lvgl_sys::lv_part_t {
         }
     }
 }
+
+bitflags! {
+    /// Flags changing how an object behaves, see `NativeObject::add_flag()`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct ObjFlag: u32 {
+        const HIDDEN = lvgl_sys::LV_OBJ_FLAG_HIDDEN;
+        const CLICKABLE = lvgl_sys::LV_OBJ_FLAG_CLICKABLE;
+        const CLICK_FOCUSABLE = lvgl_sys::LV_OBJ_FLAG_CLICK_FOCUSABLE;
+        const CHECKABLE = lvgl_sys::LV_OBJ_FLAG_CHECKABLE;
+        const SCROLLABLE = lvgl_sys::LV_OBJ_FLAG_SCROLLABLE;
+        const SCROLL_ELASTIC = lvgl_sys::LV_OBJ_FLAG_SCROLL_ELASTIC;
+        const SCROLL_MOMENTUM = lvgl_sys::LV_OBJ_FLAG_SCROLL_MOMENTUM;
+        const SCROLL_ONE = lvgl_sys::LV_OBJ_FLAG_SCROLL_ONE;
+        const SCROLL_CHAIN_HOR = lvgl_sys::LV_OBJ_FLAG_SCROLL_CHAIN_HOR;
+        const SCROLL_CHAIN_VER = lvgl_sys::LV_OBJ_FLAG_SCROLL_CHAIN_VER;
+        const SCROLL_CHAIN = lvgl_sys::LV_OBJ_FLAG_SCROLL_CHAIN;
+        const SCROLL_ON_FOCUS = lvgl_sys::LV_OBJ_FLAG_SCROLL_ON_FOCUS;
+        const SCROLL_WITH_ARROW = lvgl_sys::LV_OBJ_FLAG_SCROLL_WITH_ARROW;
+        const SNAPPABLE = lvgl_sys::LV_OBJ_FLAG_SNAPPABLE;
+        const PRESS_LOCK = lvgl_sys::LV_OBJ_FLAG_PRESS_LOCK;
+        const EVENT_BUBBLE = lvgl_sys::LV_OBJ_FLAG_EVENT_BUBBLE;
+        const GESTURE_BUBBLE = lvgl_sys::LV_OBJ_FLAG_GESTURE_BUBBLE;
+        const ADV_HITTEST = lvgl_sys::LV_OBJ_FLAG_ADV_HITTEST;
+        const IGNORE_LAYOUT = lvgl_sys::LV_OBJ_FLAG_IGNORE_LAYOUT;
+        const FLOATING = lvgl_sys::LV_OBJ_FLAG_FLOATING;
+        const OVERFLOW_VISIBLE = lvgl_sys::LV_OBJ_FLAG_OVERFLOW_VISIBLE;
+        const LAYOUT_1 = lvgl_sys::LV_OBJ_FLAG_LAYOUT_1;
+        const LAYOUT_2 = lvgl_sys::LV_OBJ_FLAG_LAYOUT_2;
+        const WIDGET_1 = lvgl_sys::LV_OBJ_FLAG_WIDGET_1;
+        const WIDGET_2 = lvgl_sys::LV_OBJ_FLAG_WIDGET_2;
+        const USER_1 = lvgl_sys::LV_OBJ_FLAG_USER_1;
+        const USER_2 = lvgl_sys::LV_OBJ_FLAG_USER_2;
+        const USER_3 = lvgl_sys::LV_OBJ_FLAG_USER_3;
+        const USER_4 = lvgl_sys::LV_OBJ_FLAG_USER_4;
+    }
+}
+
+impl From<ObjFlag> for lvgl_sys::lv_obj_flag_t {
+    fn from(value: ObjFlag) -> Self {
+        value.bits() as lvgl_sys::lv_obj_flag_t
+    }
+}
+
+bitflags! {
+    /// The states an object can be in, which select the styles it is drawn
+    /// with. See `NativeObject::add_state()`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct ObjState: u32 {
+        const DEFAULT = lvgl_sys::LV_STATE_DEFAULT;
+        const CHECKED = lvgl_sys::LV_STATE_CHECKED;
+        const FOCUSED = lvgl_sys::LV_STATE_FOCUSED;
+        const FOCUS_KEY = lvgl_sys::LV_STATE_FOCUS_KEY;
+        const EDITED = lvgl_sys::LV_STATE_EDITED;
+        const HOVERED = lvgl_sys::LV_STATE_HOVERED;
+        const PRESSED = lvgl_sys::LV_STATE_PRESSED;
+        const SCROLLED = lvgl_sys::LV_STATE_SCROLLED;
+        const DISABLED = lvgl_sys::LV_STATE_DISABLED;
+        const USER_1 = lvgl_sys::LV_STATE_USER_1;
+        const USER_2 = lvgl_sys::LV_STATE_USER_2;
+        const USER_3 = lvgl_sys::LV_STATE_USER_3;
+        const USER_4 = lvgl_sys::LV_STATE_USER_4;
+        const ANY = lvgl_sys::LV_STATE_ANY;
+    }
+}
+
+impl From<ObjState> for lvgl_sys::lv_state_t {
+    fn from(value: ObjState) -> Self {
+        value.bits() as lvgl_sys::lv_state_t
+    }
+}
+
+/// A part of an object along with the states a style applies in. Built with
+/// `Part::Main | ObjState::PRESSED`; a lone `Part` applies in every state.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Selector(lvgl_sys::lv_style_selector_t);
+
+impl Selector {
+    pub fn new(part: Part, state: ObjState) -> Self {
+        Self(lvgl_sys::lv_part_t::from(part) | state.bits())
+    }
+}
+
+impl From<Part> for Selector {
+    fn from(part: Part) -> Self {
+        Self::new(part, ObjState::DEFAULT)
+    }
+}
+
+impl BitOr<ObjState> for Part {
+    type Output = Selector;
+
+    fn bitor(self, state: ObjState) -> Selector {
+        Selector::new(self, state)
+    }
+}
+
+impl From<Selector> for lvgl_sys::lv_style_selector_t {
+    fn from(value: Selector) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::misc::area::{pct, LV_SIZE_CONTENT};
+    use crate::widgets::Btn;
+    use crate::Display;
+
+    #[test]
+    fn obj_add_and_clear_flag() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        assert!(!btn.has_flag(ObjFlag::HIDDEN));
+        assert!(btn.has_flag(ObjFlag::CLICKABLE));
+
+        btn.add_flag(ObjFlag::HIDDEN | ObjFlag::FLOATING);
+        assert!(btn.has_flag(ObjFlag::HIDDEN));
+        assert!(btn.has_flag(ObjFlag::HIDDEN | ObjFlag::FLOATING));
+
+        btn.clear_flag(ObjFlag::HIDDEN);
+        assert!(!btn.has_flag(ObjFlag::HIDDEN));
+        assert!(!btn.has_flag(ObjFlag::HIDDEN | ObjFlag::FLOATING));
+        assert!(btn.has_flag(ObjFlag::FLOATING));
+    }
+
+    #[test]
+    fn obj_add_and_clear_state() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        assert!(!btn.has_state(ObjState::DISABLED));
+
+        btn.add_state(ObjState::DISABLED);
+        assert!(btn.has_state(ObjState::DISABLED));
+        assert!(!btn.has_state(ObjState::DISABLED | ObjState::CHECKED));
+
+        btn.add_state(ObjState::CHECKED);
+        assert!(btn.has_state(ObjState::DISABLED | ObjState::CHECKED));
+
+        btn.clear_state(ObjState::DISABLED);
+        assert!(!btn.has_state(ObjState::DISABLED));
+        assert!(btn.has_state(ObjState::CHECKED));
+    }
+
+    #[test]
+    fn obj_children() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut parent = Obj::create(&mut screen).unwrap();
+        assert_eq!(parent.child_count(), 0);
+        assert!(parent.child(0).is_none());
+
+        let first = Btn::create(&mut parent).unwrap();
+        let second = Btn::create(&mut parent).unwrap();
+        assert_eq!(parent.child_count(), 2);
+        assert_eq!(parent.child(0).unwrap().raw(), first.raw());
+        assert_eq!(parent.child(1).unwrap().raw(), second.raw());
+        assert!(parent.child(2).is_none());
+        assert_eq!((first.index(), second.index()), (0, 1));
+    }
+
+    #[test]
+    fn obj_delete_and_clean() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut parent = Obj::create(&mut screen).unwrap();
+        let first = Btn::create(&mut parent).unwrap();
+        let second = Btn::create(&mut parent).unwrap();
+        Btn::create(&mut parent).unwrap();
+        assert_eq!(parent.child_count(), 3);
+
+        first.delete();
+        assert_eq!(parent.child_count(), 2);
+        assert_eq!(parent.child(0).unwrap().raw(), second.raw());
+
+        parent.clean();
+        assert_eq!(parent.child_count(), 0);
+    }
+
+    fn sized_btn(parent: &mut impl NativeObject, w: i16, h: i16) -> Btn<'_> {
+        let mut btn = Btn::create(parent).unwrap();
+        btn.set_size(Size::Px(w), Size::Px(h));
+        btn
+    }
+
+    #[test]
+    fn obj_align_and_align_to() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut base = sized_btn(&mut screen, 60, 20);
+        base.align(Align::TopLeft, 7, 3);
+        let mut below = sized_btn(&mut screen, 30, 10);
+        below.align_to(&base, Align::OutBottomMid, 0, 5);
+        let mut right = sized_btn(&mut screen, 30, 10);
+        right.align_to(&base, Align::OutRightMid, 4, 0);
+
+        unsafe {
+            lvgl_sys::lv_obj_update_layout(screen.raw().as_ptr());
+            assert_eq!(lvgl_sys::lv_obj_get_x(base.raw().as_ptr()), 7);
+            assert_eq!(lvgl_sys::lv_obj_get_y(base.raw().as_ptr()), 3);
+        }
+        let (base_x, base_y, _, _) = coords(&base);
+        let (x, y, _, _) = coords(&below);
+        assert_eq!((x - base_x, y - base_y), ((60 - 30) / 2, 20 + 5));
+        let (x, y, _, _) = coords(&right);
+        assert_eq!((x - base_x, y - base_y), (60 + 4, (20 - 10) / 2));
+    }
+
+    #[test]
+    fn obj_set_size() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let style_size = |btn: &Btn| unsafe {
+            (
+                lvgl_sys::lv_obj_get_style_width(btn.raw().as_ptr(), 0),
+                lvgl_sys::lv_obj_get_style_height(btn.raw().as_ptr(), 0),
+            )
+        };
+
+        btn.set_size(Size::Px(40), Size::Px(30));
+        assert_eq!(style_size(&btn), (40, 30));
+        assert_eq!(coords(&btn).2, 40);
+
+        btn.set_size(Size::Pct(50), Size::Content);
+        assert_eq!(style_size(&btn), (pct(50), LV_SIZE_CONTENT));
+
+        btn.set_width(Size::Content);
+        btn.set_height(Size::Pct(10));
+        assert_eq!(style_size(&btn), (LV_SIZE_CONTENT, pct(10)));
+    }
+
+    #[test]
+    fn create_before_init_fails() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        unsafe { crate::deinit() };
+        assert!(!crate::is_init());
+        assert_eq!(Btn::create(&mut screen).err(), Some(LvError::Uninitialized));
+        assert_eq!(Obj::blank().err(), Some(LvError::Uninitialized));
+
+        crate::init();
+        crate::init();
+        assert!(crate::is_init());
+        assert!(Obj::blank().is_ok());
+    }
+
+    #[test]
+    fn raw_ptr_round_trip() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let btn = Btn::create(&mut screen).unwrap();
+        let ptr = btn.as_raw_ptr();
+        assert!(unsafe { lvgl_sys::lv_obj_check_type(ptr, &lvgl_sys::lv_btn_class) });
+
+        let same = unsafe { Btn::from_raw_ptr(ptr) }.unwrap();
+        assert_eq!(same.raw(), btn.raw());
+        let obj = unsafe { Obj::from_raw_ptr(ptr) }.unwrap();
+        assert_eq!(obj.as_raw_ptr(), ptr);
+        assert!(unsafe { Btn::from_raw_ptr(ptr::null_mut()) }.is_none());
+    }
+
+    #[test]
+    fn obj_set_local_style() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        btn.set_style_opa(Opacity::OPA_50, Part::Main);
+        btn.set_style_opa(Opacity::OPA_20, Part::Main | ObjState::PRESSED);
+
+        let opa = |part: Part| unsafe {
+            lvgl_sys::lv_obj_get_style_prop(
+                btn.raw().as_ptr(),
+                part.into(),
+                lvgl_sys::lv_style_prop_t_LV_STYLE_OPA,
+            )
+            .num
+        };
+        assert_eq!(opa(Part::Main), 127);
+        // Only the main part is styled
+        assert_eq!(opa(Part::Knob), 255);
+
+        btn.add_state(ObjState::PRESSED);
+        assert_eq!(opa(Part::Main), 51);
+    }
+
+    #[test]
+    fn obj_add_and_remove_style() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let mut style = Style::default();
+        style.set_opa(Opacity::OPA_50);
+        let style = style.leak();
+
+        let opa = |btn: &Btn| unsafe {
+            lvgl_sys::lv_obj_get_style_prop(
+                btn.raw().as_ptr(),
+                Part::Main.into(),
+                lvgl_sys::lv_style_prop_t_LV_STYLE_OPA,
+            )
+            .num
+        };
+        btn.add_style(Part::Main, style);
+        assert_eq!(opa(&btn), 127);
+        crate::style::report_style_change(Some(style));
+
+        btn.remove_style(Part::Main, style);
+        assert_eq!(opa(&btn), 255);
+    }
+
+    #[test]
+    fn obj_add_style_for_state() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let mut style = Style::default();
+        style.set_opa(Opacity::OPA_50);
+        let style = style.leak();
+
+        let opa = |btn: &Btn| unsafe {
+            lvgl_sys::lv_obj_get_style_prop(
+                btn.raw().as_ptr(),
+                Part::Main.into(),
+                lvgl_sys::lv_style_prop_t_LV_STYLE_OPA,
+            )
+            .num
+        };
+        btn.add_style(Part::Main | ObjState::PRESSED, style);
+        assert_eq!(opa(&btn), 255);
+        btn.add_state(ObjState::PRESSED);
+        assert_eq!(opa(&btn), 127);
+
+        // Only a style added with the same selector is removed
+        btn.remove_style(Part::Main, style);
+        assert_eq!(opa(&btn), 127);
+        btn.remove_style(Part::Main | ObjState::PRESSED, style);
+        assert_eq!(opa(&btn), 255);
+    }
+
+    #[test]
+    #[cfg_attr(not(all(feature = "std", debug_assertions)), ignore)]
+    fn obj_used_from_another_thread_panics() {
+        crate::tests::initialize_test(true);
+        let result = std::thread::spawn(|| {
+            let mut screen = crate::display::get_scr_act(None).unwrap();
+            Btn::create(&mut screen).map(|_| ())
+        })
+        .join();
+        assert!(result.is_err());
+
+        // The thread which initialized LVGL can still use it
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        Btn::create(&mut screen).unwrap();
+    }
+
+    #[test]
+    #[cfg_attr(not(all(feature = "std", debug_assertions)), ignore)]
+    fn first_thread_using_lvgl_claims_it() {
+        crate::tests::initialize_test(true);
+        // Like after the automatic initialization, which claims no thread
+        crate::claim_thread(false);
+        let result = std::thread::spawn(|| {
+            let mut screen = crate::display::get_scr_act(None).unwrap();
+            Btn::create(&mut screen).map(|_| ())
+        })
+        .join();
+        assert!(matches!(result, Ok(Ok(()))));
+        crate::claim_thread(true);
+    }
+
+    #[test]
+    fn selector_combines_part_and_state() {
+        assert_eq!(
+            lvgl_sys::lv_style_selector_t::from(Part::Knob | ObjState::CHECKED),
+            lvgl_sys::LV_PART_KNOB | lvgl_sys::LV_STATE_CHECKED
+        );
+        assert_eq!(Selector::from(Part::Main), Part::Main | ObjState::DEFAULT);
+    }
+
+    fn coords(obj: &impl NativeObject) -> (i16, i16, i16, i16) {
+        unsafe {
+            lvgl_sys::lv_obj_update_layout(obj.raw().as_ptr());
+            let area = (*obj.raw().as_ptr()).coords;
+            (
+                area.x1,
+                area.y1,
+                area.x2 - area.x1 + 1,
+                area.y2 - area.y1 + 1,
+            )
+        }
+    }
+
+    fn no_gaps(obj: &mut impl NativeObject) {
+        unsafe {
+            lvgl_sys::lv_obj_set_size(obj.raw().as_mut(), 200, 200);
+        }
+        obj.set_style_pad_column(0, Part::Main);
+        obj.set_style_pad_row(0, Part::Main);
+    }
+
+    #[test]
+    fn obj_set_grid_cell() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut cont = Obj::create(&mut screen).unwrap();
+        no_gaps(&mut cont);
+        cont.set_grid_dsc(
+            unsafe { CoordDesc::from_values([50, 60], true) }.leak(),
+            unsafe { CoordDesc::from_values([30, 40], true) }.leak(),
+        );
+
+        let mut first = Btn::create(&mut cont).unwrap();
+        first.set_grid_cell(GridAlign::STRETCH, 0, 1, GridAlign::STRETCH, 0, 1);
+        let mut second = Btn::create(&mut cont).unwrap();
+        second.set_grid_cell(GridAlign::STRETCH, 1, 1, GridAlign::STRETCH, 1, 1);
+
+        let (x0, y0, w0, h0) = coords(&first);
+        let (x1, y1, w1, h1) = coords(&second);
+        assert_eq!((w0, h0), (50, 30));
+        assert_eq!((w1, h1), (60, 40));
+        assert_eq!((x1 - x0, y1 - y0), (50, 30));
+    }
+
+    #[test]
+    fn obj_set_grid_dsc() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut cont = Obj::create(&mut screen).unwrap();
+        no_gaps(&mut cont);
+        let cols = unsafe { CoordDesc::from_values([50, 60, 70], true) }.leak();
+        let rows = unsafe { CoordDesc::from_values([30, 40, 50], true) }.leak();
+        cont.set_grid_dsc(cols, rows);
+
+        let mut cells = Vec::new();
+        for row in 0..3 {
+            for col in 0..3 {
+                let mut cell = Btn::create(&mut cont).unwrap();
+                cell.set_grid_cell(GridAlign::STRETCH, col, 1, GridAlign::STRETCH, row, 1);
+                cells.push(cell);
+            }
+        }
+
+        let (x0, y0, _, _) = coords(&cells[0]);
+        for (i, cell) in cells.iter().enumerate() {
+            let (x, y, w, h) = coords(cell);
+            let (col, row) = (i % 3, i / 3);
+            assert_eq!((w, h), (cols.values()[col], rows.values()[row]));
+            assert_eq!((x - x0, y - y0), ([0, 50, 110][col], [0, 30, 70][row]));
+        }
+    }
+
+    #[test]
+    fn obj_set_flex_flow() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut cont = Obj::create(&mut screen).unwrap();
+        no_gaps(&mut cont);
+        cont.set_flex_flow(FlexFlow::ROW);
+
+        let mut first = Btn::create(&mut cont).unwrap();
+        let mut second = Btn::create(&mut cont).unwrap();
+        unsafe {
+            lvgl_sys::lv_obj_set_size(first.raw().as_mut(), 40, 20);
+            lvgl_sys::lv_obj_set_size(second.raw().as_mut(), 40, 20);
+        }
+        let (x0, y0, _, _) = coords(&first);
+        let (x1, y1, _, _) = coords(&second);
+        assert_eq!((x1 - x0, y1 - y0), (40, 0));
+
+        cont.set_flex_flow(FlexFlow::COLUMN);
+        let (x0, y0, _, _) = coords(&first);
+        let (x1, y1, _, _) = coords(&second);
+        assert_eq!((x1 - x0, y1 - y0), (0, 20));
+    }
+}
@@ -9,17 +9,22 @@
 //! let mut my_style = Style::default();
 //! my_style.set_text_color(Color::from_rgb((0, 0, 0)));
 //!
-//! // my_widget.add_style(my_style.into_raw(), Part::Main.into());
+//! // my_widget.add_style(Part::Main, my_style.leak());
 //! // ...
 //! ```
 //! All methods on the `Style` type directly lower to their C LVGL
 //! counterparts.
 
-use crate::{font::Font, Align, Box, Color, TextAlign};
+use crate::misc::anim::AnimPath;
+use crate::{font::Font, Align, Box, Color, LvError, LvResult, TextAlign};
 use core::fmt;
 use core::fmt::Debug;
 use core::mem::{self, MaybeUninit};
-use cty::c_uint;
+use core::num::TryFromIntError;
+use core::ptr;
+use core::time::Duration;
+use cstr_core::CStr;
+use cty::{c_uint, c_void};
 use paste::paste;
 
 pub enum Themes {
@@ -28,23 +33,89 @@ pub enum Themes {
 
 /// An LVGL `lv_style_t`. Allows for styling objects. Once created, a `Style`
 /// should be configured and then added to an object.
-#[derive(Clone)]
 pub struct Style {
     pub(crate) raw: Box<lvgl_sys::lv_style_t>,
 }
 
 impl Style {
     pub fn into_raw(self) -> &'static mut lvgl_sys::lv_style_t {
-        unsafe { self.raw.into_raw().as_mut().unwrap() }
+        // Ownership moves to LVGL, so the style must not be reset on drop
+        let style = mem::ManuallyDrop::new(self);
+        unsafe { ptr::read(&style.raw).into_raw().as_mut().unwrap() }
+    }
+
+    /// Moves the style into LVGL memory for the rest of the program, so it
+    /// can be added to objects.
+    pub fn leak(self) -> &'static Self {
+        unsafe { &*Box::into_raw(Box::new(self)) }
+    }
+
+    /// Copies every property set on `other` into this style, overwriting the
+    /// ones both styles have set.
+    ///
+    /// ```
+    /// use lvgl::style::Style;
+    ///
+    /// lvgl::init();
+    /// let mut base = Style::default();
+    /// base.set_radius(4);
+    /// let mut wide = Style::default();
+    /// wide.copy_from(&base);
+    /// wide.set_width(200);
+    /// ```
+    pub fn copy_from(&mut self, other: &Style) {
+        for flag in <StyleProp as bitflags::Flags>::FLAGS {
+            let prop = flag.value().bits();
+            let mut value = MaybeUninit::<lvgl_sys::lv_style_value_t>::uninit();
+            unsafe {
+                let result = lvgl_sys::lv_style_get_prop(&*other.raw, prop, value.as_mut_ptr());
+                if result as u32 == lvgl_sys::LV_STYLE_RES_FOUND {
+                    lvgl_sys::lv_style_set_prop(self.raw.as_mut(), prop, value.assume_init());
+                }
+            }
+        }
+    }
+
+    /// Removes all properties from the style and frees the memory used to
+    /// store them.
+    pub fn reset(&mut self) {
+        unsafe {
+            lvgl_sys::lv_style_reset(self.raw.as_mut());
+        }
+    }
+}
+
+/// Refreshes the objects using `style` after it was changed, or all objects
+/// with `None`.
+pub fn report_style_change(style: Option<&Style>) {
+    let raw = style.map_or(ptr::null_mut(), |style| &*style.raw as *const _ as *mut _);
+    unsafe { lvgl_sys::lv_obj_report_style_change(raw) }
+}
+
+impl Clone for Style {
+    fn clone(&self) -> Self {
+        let mut style = Style::default();
+        style.copy_from(self);
+        style
+    }
+}
+
+impl Drop for Style {
+    fn drop(&mut self) {
+        self.reset();
     }
 }
 
 impl Debug for Style {
-    // TODO: Decode and dump style values
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("Style")
-            .field("raw", &"!! LVGL lv_style_t ptr !!")
-            .finish()
+        let mut dbg = f.debug_struct("Style");
+        for flag in <StyleProp as bitflags::Flags>::FLAGS {
+            let value = self.get_prop(StyleProp::from_bits_retain(flag.value().bits()));
+            if value.is_some() {
+                dbg.field(flag.name(), &value);
+            }
+        }
+        dbg.finish()
     }
 }
 
@@ -85,6 +156,168 @@ impl From<Opacity> for u8 {
     }
 }
 
+/// The direction of a background gradient.
+///
+/// ```
+/// use lvgl::style::{GradDir, Style};
+///
+/// lvgl::init();
+/// let mut style = Style::default();
+/// style.set_bg_grad_dir(GradDir::Ver);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradDir {
+    None,
+    Ver,
+    Hor,
+}
+
+impl From<GradDir> for u8 {
+    fn from(value: GradDir) -> Self {
+        let native = match value {
+            GradDir::None => lvgl_sys::LV_GRAD_DIR_NONE,
+            GradDir::Ver => lvgl_sys::LV_GRAD_DIR_VER,
+            GradDir::Hor => lvgl_sys::LV_GRAD_DIR_HOR,
+        };
+        native as u8
+    }
+}
+
+/// How a drawing is blended with what is already behind it.
+///
+/// ```
+/// use lvgl::style::{BlendMode, Style};
+///
+/// lvgl::init();
+/// let mut style = Style::default();
+/// style.set_blend_mode(BlendMode::Additive);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    Normal,
+    Additive,
+    Subtractive,
+    Multiply,
+    Replace,
+}
+
+impl From<BlendMode> for u8 {
+    fn from(value: BlendMode) -> Self {
+        let native = match value {
+            BlendMode::Normal => lvgl_sys::LV_BLEND_MODE_NORMAL,
+            BlendMode::Additive => lvgl_sys::LV_BLEND_MODE_ADDITIVE,
+            BlendMode::Subtractive => lvgl_sys::LV_BLEND_MODE_SUBTRACTIVE,
+            BlendMode::Multiply => lvgl_sys::LV_BLEND_MODE_MULTIPLY,
+            BlendMode::Replace => lvgl_sys::LV_BLEND_MODE_REPLACE,
+        };
+        native as u8
+    }
+}
+
+/// The dithering algorithm used for background gradients.
+///
+/// ```
+/// use lvgl::style::{DitherMode, Style};
+///
+/// lvgl::init();
+/// let mut style = Style::default();
+/// style.set_bg_dither_mode(DitherMode::Ordered);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DitherMode {
+    None,
+    Ordered,
+    ErrDiff,
+}
+
+impl From<DitherMode> for u8 {
+    fn from(value: DitherMode) -> Self {
+        let native = match value {
+            DitherMode::None => lvgl_sys::LV_DITHER_NONE,
+            DitherMode::Ordered => lvgl_sys::LV_DITHER_ORDERED,
+            DitherMode::ErrDiff => lvgl_sys::LV_DITHER_ERR_DIFF,
+        };
+        native as u8
+    }
+}
+
+bitflags! {
+    /// The sides of an object a border is drawn on.
+    ///
+    /// ```
+    /// use lvgl::style::{BorderSide, Style};
+    ///
+    /// lvgl::init();
+    /// let mut style = Style::default();
+    /// style.set_border_side(BorderSide::TOP | BorderSide::BOTTOM);
+    /// ```
+    pub struct BorderSide: u32 {
+        const NONE = lvgl_sys::LV_BORDER_SIDE_NONE;
+        const BOTTOM = lvgl_sys::LV_BORDER_SIDE_BOTTOM;
+        const TOP = lvgl_sys::LV_BORDER_SIDE_TOP;
+        const LEFT = lvgl_sys::LV_BORDER_SIDE_LEFT;
+        const RIGHT = lvgl_sys::LV_BORDER_SIDE_RIGHT;
+        const FULL = lvgl_sys::LV_BORDER_SIDE_FULL;
+        const INTERNAL = lvgl_sys::LV_BORDER_SIDE_INTERNAL;
+    }
+}
+
+impl From<BorderSide> for u8 {
+    fn from(value: BorderSide) -> Self {
+        value.bits() as u8
+    }
+}
+
+bitflags! {
+    /// Decorations drawn on text.
+    ///
+    /// ```
+    /// use lvgl::style::{Style, TextDecor};
+    ///
+    /// lvgl::init();
+    /// let mut style = Style::default();
+    /// style.set_text_decor(TextDecor::UNDERLINE);
+    /// ```
+    pub struct TextDecor: u32 {
+        const NONE = lvgl_sys::LV_TEXT_DECOR_NONE;
+        const UNDERLINE = lvgl_sys::LV_TEXT_DECOR_UNDERLINE;
+        const STRIKETHROUGH = lvgl_sys::LV_TEXT_DECOR_STRIKETHROUGH;
+    }
+}
+
+impl From<TextDecor> for u8 {
+    fn from(value: TextDecor) -> Self {
+        value.bits() as u8
+    }
+}
+
+/// The base direction of text, for right-to-left languages.
+///
+/// ```
+/// use lvgl::style::{BaseDir, Style};
+///
+/// lvgl::init();
+/// let mut style = Style::default();
+/// style.set_base_dir(BaseDir::Rtl);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaseDir {
+    Ltr,
+    Rtl,
+    Auto,
+}
+
+impl From<BaseDir> for u8 {
+    fn from(value: BaseDir) -> Self {
+        let native = match value {
+            BaseDir::Ltr => lvgl_sys::LV_BASE_DIR_LTR,
+            BaseDir::Rtl => lvgl_sys::LV_BASE_DIR_RTL,
+            BaseDir::Auto => lvgl_sys::LV_BASE_DIR_AUTO,
+        };
+        native as u8
+    }
+}
+
 bitflags! {
     pub struct GridAlign: c_uint {
         const START = lvgl_sys::lv_grid_align_t_LV_GRID_ALIGN_START;
@@ -173,8 +406,150 @@ impl From<Layout> for u16 {
     }
 }
 
+/// The source of an image, for use with the `set_bg_img_src()` method on
+/// `Style` objects. LVGL keeps the pointer, so the source must be `'static`.
+#[derive(Clone, Copy)]
+pub enum ImageSrc {
+    /// An image descriptor, usually generated by the LVGL image converter.
+    Dsc(&'static lvgl_sys::lv_img_dsc_t),
+    /// A built-in symbol, like `lvgl_sys::LV_SYMBOL_OK`.
+    Symbol(&'static CStr),
+    /// A path to an image file, read through the LVGL file system.
+    File(&'static CStr),
+}
+
+impl From<ImageSrc> for *const c_void {
+    fn from(value: ImageSrc) -> Self {
+        match value {
+            ImageSrc::Dsc(dsc) => dsc as *const _ as *const c_void,
+            ImageSrc::Symbol(text) | ImageSrc::File(text) => text.as_ptr() as *const c_void,
+        }
+    }
+}
+
+/// A transition of style properties, for use with the `set_transition()`
+/// method on `Style` objects. LVGL keeps a pointer to the transition, so it
+/// has to be `leak()`ed before use.
+///
+/// ```
+/// use core::time::Duration;
+/// use lvgl::misc::anim::AnimPath;
+/// use lvgl::style::{Style, StyleProp, StyleTransition};
+///
+/// lvgl::init();
+/// let transition = StyleTransition::new(
+///     [StyleProp::WIDTH, StyleProp::HEIGHT],
+///     AnimPath::EaseOut,
+///     Duration::from_millis(300),
+///     Duration::ZERO,
+/// )
+/// .unwrap()
+/// .leak();
+/// let mut style = Style::default();
+/// style.set_transition(transition);
+/// ```
+pub struct StyleTransition<const N: usize> {
+    raw: Box<TransitionDsc<N>>,
+}
+
+#[repr(C)]
+struct TransitionDsc<const N: usize> {
+    dsc: lvgl_sys::lv_style_transition_dsc_t,
+    // The zero-terminated property list `dsc` points to
+    props: [lvgl_sys::lv_style_prop_t; N],
+    tail: lvgl_sys::lv_style_prop_t,
+}
+
+impl<const N: usize> StyleTransition<N> {
+    /// Creates a transition of `props`, whose values follow `path`.
+    pub fn new(
+        props: [StyleProp; N],
+        path: AnimPath,
+        time: Duration,
+        delay: Duration,
+    ) -> Result<Self, TryFromIntError> {
+        let time = time.as_millis().try_into()?;
+        let delay = delay.as_millis().try_into()?;
+        let mut raw = Box::new(TransitionDsc {
+            dsc: unsafe { MaybeUninit::zeroed().assume_init() },
+            props: props.map(|prop| prop.bits()),
+            tail: lvgl_sys::lv_style_prop_t_LV_STYLE_PROP_INV,
+        });
+        let props = raw.props.as_ptr();
+        unsafe {
+            lvgl_sys::lv_style_transition_dsc_init(
+                &mut raw.dsc,
+                props,
+                path.into(),
+                time,
+                delay,
+                ptr::null_mut(),
+            );
+        }
+        Ok(Self { raw })
+    }
+
+    /// Moves the transition into LVGL memory for the rest of the program.
+    pub fn leak(self) -> &'static Self {
+        unsafe { &*Box::into_raw(Box::new(self)) }
+    }
+}
+
+/// A background gradient, for use with the `set_bg_grad()` method on `Style`
+/// objects. LVGL keeps a pointer to the gradient, so it has to be `leak()`ed
+/// before use.
+///
+/// ```
+/// use lvgl::style::{GradDir, Gradient, Style};
+/// use lvgl::Color;
+///
+/// lvgl::init();
+/// let gradient = Gradient::new(GradDir::Hor)
+///     .with_stop(Color::from_rgb((255, 0, 0)), 0)
+///     .unwrap()
+///     .with_stop(Color::from_rgb((0, 0, 255)), 255)
+///     .unwrap()
+///     .leak();
+/// let mut style = Style::default();
+/// style.set_bg_grad(gradient);
+/// ```
+pub struct Gradient {
+    raw: Box<lvgl_sys::lv_grad_dsc_t>,
+}
+
+impl Gradient {
+    /// Creates a gradient without any stops running in direction `dir`.
+    pub fn new(dir: GradDir) -> Self {
+        let mut raw: Box<lvgl_sys::lv_grad_dsc_t> =
+            Box::new(unsafe { MaybeUninit::zeroed().assume_init() });
+        raw.set_dir(dir.into());
+        Self { raw }
+    }
+
+    /// Adds a stop of `color` at `frac`, from 0 (start) to 255 (end). Fails
+    /// with `LvError::InvalidArgument` once `LV_GRADIENT_MAX_STOPS` stops are
+    /// set.
+    pub fn with_stop(mut self, color: Color, frac: u8) -> LvResult<Self> {
+        let count = self.raw.stops_count as usize;
+        let stop = self
+            .raw
+            .stops
+            .get_mut(count)
+            .ok_or(LvError::InvalidArgument)?;
+        stop.color = color.raw;
+        stop.frac = frac;
+        self.raw.stops_count += 1;
+        Ok(self)
+    }
+
+    /// Moves the gradient into LVGL memory for the rest of the program.
+    pub fn leak(self) -> &'static Self {
+        unsafe { &*Box::into_raw(Box::new(self)) }
+    }
+}
+
 /// A coordinate array, for use with `set_grid_*_dsc_array()` methods on
-/// `Style` objects.
+/// `Style` objects and with `NativeObject::set_grid_dsc()`.
 #[derive(Clone)]
 #[repr(C)]
 pub struct CoordDesc<const N: usize> {
@@ -204,6 +579,11 @@ impl<const N: usize> CoordDesc<N> {
     pub fn values(&self) -> [i16; N] {
         self.clone().inner
     }
+
+    /// Moves the descriptor into LVGL memory for the rest of the program.
+    pub fn leak(self) -> &'static Self {
+        unsafe { &*Box::into_raw(Box::new(self)) }
+    }
 }
 
 impl<const N: usize> From<&CoordDesc<N>> for *const i16 {
@@ -217,7 +597,7 @@ pub enum StyleValues {
     Num(i32),
     Color(Color),
     Opacity(Opacity),
-    //Align(Align),
+    Align(Align),
     None,
 }
 
@@ -227,6 +607,21 @@ impl StyleValues {
     }
 }
 
+impl Debug for StyleValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StyleValues::Num(num) => write!(f, "{}", num),
+            StyleValues::Color(color) => {
+                let (r, g, b) = color.to_rgb();
+                write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+            }
+            StyleValues::Opacity(opa) => write!(f, "{}", opa.bits()),
+            StyleValues::Align(align) => write!(f, "{:?}", align),
+            StyleValues::None => write!(f, "None"),
+        }
+    }
+}
+
 /*impl StyleValues {
     pub fn num(&self) -> i32 {
         self.num
@@ -400,8 +795,15 @@ macro_rules! gen_lv_style_generic {
 
 impl Style {
     pub fn get_prop(&self, prop: StyleProp) -> StyleValues {
+        self.try_get_prop(prop).unwrap_or(StyleValues::None)
+    }
+
+    /// Returns the value of `prop`, or `None` if the property isn't set on
+    /// the style. Properties of a kind `StyleValues` can't hold yet are
+    /// returned as `Some(StyleValues::None)`.
+    pub fn try_get_prop(&self, prop: StyleProp) -> Option<StyleValues> {
         let mut raw_ret = MaybeUninit::<lvgl_sys::lv_style_value_t>::uninit();
-        let mut ret = match prop {
+        let ret = match prop {
             StyleProp::WIDTH
             | StyleProp::MIN_WIDTH
             | StyleProp::MAX_WIDTH
@@ -457,28 +859,30 @@ impl Style {
             | StyleProp::ARC_COLOR
             | StyleProp::TEXT_COLOR => StyleValues::Color(Color::default()),
 
+            StyleProp::ALIGN => StyleValues::Align(Align::Center),
+
             _ => StyleValues::None,
         };
 
         let ptr = raw_ret.as_mut_ptr() as *mut _;
-        let result = unsafe {
-            lvgl_sys::lv_style_get_prop(self.raw.clone().into_raw() as *const _, prop.bits(), ptr)
-        };
+        let result = unsafe { lvgl_sys::lv_style_get_prop(&*self.raw, prop.bits(), ptr) };
         let raw_ret = unsafe { raw_ret.assume_init() };
-        if <u8 as Into<u32>>::into(result) == lvgl_sys::LV_RES_OK {
+        if <u8 as Into<u32>>::into(result) == lvgl_sys::LV_STYLE_RES_FOUND {
             unsafe {
-                ret = match ret {
+                Some(match ret {
                     StyleValues::Num(_) => StyleValues::Num(raw_ret.num),
                     StyleValues::Opacity(_) => StyleValues::Opacity(Opacity::from_bits_retain(
                         raw_ret.num.try_into().unwrap(),
                     )),
                     StyleValues::Color(_) => StyleValues::Color(Color::from_raw(raw_ret.color)),
+                    StyleValues::Align(_) => (raw_ret.num as u8)
+                        .try_into()
+                        .map_or(StyleValues::None, StyleValues::Align),
                     _ => StyleValues::None,
-                }
+                })
             }
-            ret
         } else {
-            StyleValues::None
+            None
         }
         /*unsafe {
             let ret = lvgl_sys::lv_style_value_t {
@@ -489,6 +893,25 @@ impl Style {
         }*/
     }
 
+    /// Sets a property to the given value. `StyleValues::None` leaves the
+    /// style untouched.
+    pub fn set_prop(&mut self, prop: StyleProp, value: StyleValues) {
+        let raw_value = match value {
+            StyleValues::Num(num) => lvgl_sys::lv_style_value_t { num },
+            StyleValues::Opacity(opa) => lvgl_sys::lv_style_value_t {
+                num: opa.bits() as i32,
+            },
+            StyleValues::Color(color) => lvgl_sys::lv_style_value_t { color: color.raw },
+            StyleValues::Align(align) => lvgl_sys::lv_style_value_t {
+                num: u8::from(align) as i32,
+            },
+            StyleValues::None => return,
+        };
+        unsafe {
+            lvgl_sys::lv_style_set_prop(self.raw.as_mut(), prop.bits(), raw_value);
+        }
+    }
+
     gen_lv_style!(set_align, Align);
     //gen_lv_style!(set_anim, );
     //gen_lv_style!(set_anim_speed, );
@@ -498,25 +921,32 @@ impl Style {
     gen_lv_style!(set_arc_opa, Opacity);
     gen_lv_style!(set_arc_rounded, bool);
     gen_lv_style!(set_arc_width, i16);
-    //gen_lv_style!(set_base_dir, );
+    gen_lv_style!(set_base_dir, BaseDir);
     gen_lv_style!(set_bg_color, Color);
-    gen_lv_style!(set_bg_dither_mode, u8);
-    //gen_lv_style!(set_bg_grad, );
+    gen_lv_style!(set_bg_dither_mode, DitherMode);
+    /// Sets the background gradient, overriding `set_bg_grad_*()`.
+    #[inline]
+    pub fn set_bg_grad(&mut self, value: &'static Gradient) {
+        unsafe {
+            lvgl_sys::lv_style_set_bg_grad(self.raw.as_mut(), &*value.raw);
+        }
+    }
+
     gen_lv_style!(set_bg_grad_color, Color);
-    //gen_lv_style!(set_bg_grad_dir, );
+    gen_lv_style!(set_bg_grad_dir, GradDir);
     gen_lv_style!(set_bg_grad_stop, i16);
     gen_lv_style!(set_bg_img_opa, Opacity);
     gen_lv_style!(set_bg_img_recolor, Color);
     gen_lv_style!(set_bg_img_recolor_opa, Opacity);
-    //gen_lv_style!(set_bg_img_src, );
+    gen_lv_style!(set_bg_img_src, ImageSrc);
     gen_lv_style!(set_bg_img_tiled, bool);
     gen_lv_style!(set_bg_main_stop, i16);
     gen_lv_style!(set_bg_opa, Opacity);
-    gen_lv_style!(set_blend_mode, u8);
+    gen_lv_style!(set_blend_mode, BlendMode);
     gen_lv_style!(set_border_color, Color);
     gen_lv_style!(set_border_opa, Opacity);
     gen_lv_style!(set_border_post, bool);
-    gen_lv_style!(set_border_side, u8);
+    gen_lv_style!(set_border_side, BorderSide);
     gen_lv_style!(set_border_width, i16);
     gen_lv_style!(set_clip_corner, bool);
     //gen_lv_style!(set_color_filter_dsc, );
@@ -562,7 +992,6 @@ impl Style {
     gen_lv_style!(set_pad_right, i16);
     gen_lv_style!(set_pad_row, i16);
     gen_lv_style!(set_pad_top, i16);
-    //gen_lv_style!(set_prop, );
     //gen_lv_style!(set_prop_meta, );
     gen_lv_style!(set_radius, i16);
     gen_lv_style!(set_shadow_color, Color);
@@ -573,7 +1002,7 @@ impl Style {
     gen_lv_style!(set_shadow_width, i16);
     gen_lv_style!(set_text_align, TextAlign);
     gen_lv_style!(set_text_color, Color);
-    gen_lv_style!(set_text_decor, u8);
+    gen_lv_style!(set_text_decor, TextDecor);
     gen_lv_style!(set_text_font, Font);
     gen_lv_style!(set_text_letter_space, i16);
     gen_lv_style!(set_text_line_space, i16);
@@ -584,10 +1013,271 @@ impl Style {
     gen_lv_style!(set_transform_pivot_y, i16);
     gen_lv_style!(set_transform_width, i16);
     gen_lv_style!(set_transform_zoom, i16);
-    //gen_lv_style!(set_transition, );
+
+    /// Sets the transition played when the style gets applied.
+    #[inline]
+    pub fn set_transition<const N: usize>(&mut self, value: &'static StyleTransition<N>) {
+        unsafe {
+            lvgl_sys::lv_style_set_transition(self.raw.as_mut(), &value.raw.dsc);
+        }
+    }
+
     gen_lv_style!(set_translate_x, i16);
     gen_lv_style!(set_translate_y, i16);
     gen_lv_style!(set_width, i16);
     gen_lv_style!(set_x, i16);
     gen_lv_style!(set_y, i16);
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_bg_img_src_symbol() {
+        crate::tests::initialize_test(false);
+        let symbol = CStr::from_bytes_with_nul(lvgl_sys::LV_SYMBOL_OK).unwrap();
+        let mut style = Style::default();
+        style.set_bg_img_src(ImageSrc::Symbol(symbol));
+
+        let mut value = MaybeUninit::<lvgl_sys::lv_style_value_t>::uninit();
+        let result = unsafe {
+            lvgl_sys::lv_style_get_prop(
+                &*style.raw,
+                lvgl_sys::lv_style_prop_t_LV_STYLE_BG_IMG_SRC,
+                value.as_mut_ptr(),
+            )
+        };
+        assert_eq!(result, lvgl_sys::LV_STYLE_RES_FOUND as u8);
+        let ptr = unsafe { value.assume_init().ptr };
+        assert_eq!(ptr, symbol.as_ptr() as *const c_void);
+    }
+
+    #[test]
+    fn set_prop_get_prop_round_trip() {
+        crate::tests::initialize_test(false);
+        let mut style = Style::default();
+        style.set_prop(StyleProp::WIDTH, StyleValues::Num(120));
+        assert!(matches!(
+            style.get_prop(StyleProp::WIDTH),
+            StyleValues::Num(120)
+        ));
+
+        style.set_prop(StyleProp::HEIGHT, StyleValues::None);
+        assert!(!style.get_prop(StyleProp::HEIGHT).is_some());
+    }
+
+    #[test]
+    fn drop_and_clone_styles() {
+        crate::tests::initialize_test(false);
+        for i in 0..100 {
+            let mut style = Style::default();
+            style.set_width(i);
+            style.set_height(i);
+            style.set_radius(i);
+
+            let clone = style.clone();
+            drop(style);
+            assert!(matches!(
+                clone.get_prop(StyleProp::RADIUS),
+                StyleValues::Num(radius) if radius == i as i32
+            ));
+        }
+
+        let mut style = Style::default();
+        style.set_width(10);
+        style.reset();
+        assert!(!style.get_prop(StyleProp::WIDTH).is_some());
+    }
+
+    #[test]
+    fn set_transition() {
+        crate::tests::initialize_test(false);
+        let transition = StyleTransition::new(
+            [StyleProp::WIDTH, StyleProp::HEIGHT],
+            AnimPath::EaseOut,
+            Duration::from_millis(200),
+            Duration::from_millis(50),
+        )
+        .unwrap()
+        .leak();
+        let mut style = Style::default();
+        style.set_transition(transition);
+
+        let mut value = MaybeUninit::<lvgl_sys::lv_style_value_t>::uninit();
+        let result = unsafe {
+            lvgl_sys::lv_style_get_prop(
+                &*style.raw,
+                lvgl_sys::lv_style_prop_t_LV_STYLE_TRANSITION,
+                value.as_mut_ptr(),
+            )
+        };
+        assert_eq!(result, lvgl_sys::LV_STYLE_RES_FOUND as u8);
+        let dsc =
+            unsafe { &*(value.assume_init().ptr as *const lvgl_sys::lv_style_transition_dsc_t) };
+        assert_eq!(dsc.time, 200);
+        assert_eq!(dsc.delay, 50);
+        assert!(dsc.path_xcb.is_some());
+        let props = unsafe { core::slice::from_raw_parts(dsc.props, 3) };
+        assert_eq!(
+            props,
+            [
+                lvgl_sys::lv_style_prop_t_LV_STYLE_WIDTH,
+                lvgl_sys::lv_style_prop_t_LV_STYLE_HEIGHT,
+                lvgl_sys::lv_style_prop_t_LV_STYLE_PROP_INV
+            ]
+        );
+    }
+
+    #[test]
+    fn set_prop_get_prop_align() {
+        crate::tests::initialize_test(false);
+        let mut style = Style::default();
+        style.set_prop(StyleProp::ALIGN, StyleValues::Align(Align::OutTopMid));
+        assert!(matches!(
+            style.get_prop(StyleProp::ALIGN),
+            StyleValues::Align(Align::OutTopMid)
+        ));
+    }
+
+    #[test]
+    fn set_bg_grad_two_stops() {
+        crate::tests::initialize_test(false);
+        let gradient = Gradient::new(GradDir::Ver)
+            .with_stop(Color::from_rgb((255, 0, 0)), 0)
+            .unwrap()
+            .with_stop(Color::from_rgb((0, 0, 255)), 255)
+            .unwrap();
+        assert!(matches!(
+            gradient.with_stop(Color::from_rgb((0, 255, 0)), 128),
+            Err(LvError::InvalidArgument)
+        ));
+
+        let gradient = Gradient::new(GradDir::Ver)
+            .with_stop(Color::from_rgb((255, 0, 0)), 0)
+            .unwrap()
+            .with_stop(Color::from_rgb((0, 0, 255)), 255)
+            .unwrap()
+            .leak();
+        let mut style = Style::default();
+        style.set_bg_grad(gradient);
+
+        let mut value = MaybeUninit::<lvgl_sys::lv_style_value_t>::uninit();
+        let result = unsafe {
+            lvgl_sys::lv_style_get_prop(
+                &*style.raw,
+                lvgl_sys::lv_style_prop_t_LV_STYLE_BG_GRAD,
+                value.as_mut_ptr(),
+            )
+        };
+        assert_eq!(result, lvgl_sys::LV_STYLE_RES_FOUND as u8);
+        let dsc = unsafe { &*(value.assume_init().ptr as *const lvgl_sys::lv_grad_dsc_t) };
+        assert_eq!(dsc.stops_count, 2);
+        assert_eq!(dsc.stops[1].frac, 255);
+        assert_eq!(dsc.dir(), u8::from(GradDir::Ver));
+    }
+
+    #[test]
+    fn set_border_side_flags() {
+        crate::tests::initialize_test(false);
+        let mut style = Style::default();
+        style.set_border_side(BorderSide::TOP | BorderSide::BOTTOM);
+
+        let mut value = MaybeUninit::<lvgl_sys::lv_style_value_t>::uninit();
+        let result = unsafe {
+            lvgl_sys::lv_style_get_prop(
+                &*style.raw,
+                lvgl_sys::lv_style_prop_t_LV_STYLE_BORDER_SIDE,
+                value.as_mut_ptr(),
+            )
+        };
+        assert_eq!(result, lvgl_sys::LV_STYLE_RES_FOUND as u8);
+        let num = unsafe { value.assume_init().num };
+        assert_eq!(
+            num,
+            (lvgl_sys::LV_BORDER_SIDE_TOP | lvgl_sys::LV_BORDER_SIDE_BOTTOM) as i32
+        );
+    }
+
+    #[test]
+    fn set_text_decor_flags() {
+        crate::tests::initialize_test(false);
+        let mut style = Style::default();
+        style.set_text_decor(TextDecor::UNDERLINE | TextDecor::STRIKETHROUGH);
+
+        let mut value = MaybeUninit::<lvgl_sys::lv_style_value_t>::uninit();
+        let result = unsafe {
+            lvgl_sys::lv_style_get_prop(
+                &*style.raw,
+                lvgl_sys::lv_style_prop_t_LV_STYLE_TEXT_DECOR,
+                value.as_mut_ptr(),
+            )
+        };
+        assert_eq!(result, lvgl_sys::LV_STYLE_RES_FOUND as u8);
+        let num = unsafe { value.assume_init().num };
+        assert_eq!(
+            num,
+            (lvgl_sys::LV_TEXT_DECOR_UNDERLINE | lvgl_sys::LV_TEXT_DECOR_STRIKETHROUGH) as i32
+        );
+    }
+
+    #[test]
+    fn debug_dumps_set_props() {
+        crate::tests::initialize_test(false);
+        let mut style = Style::default();
+        assert_eq!(format!("{:?}", style), "Style");
+
+        style.set_width(120);
+        style.set_align(Align::Center);
+        style.set_bg_color(Color::from_rgb((255, 0, 0)));
+        let dump = format!("{:?}", style);
+        assert!(dump.contains("WIDTH: 120"));
+        assert!(dump.contains("ALIGN: Center"));
+        assert!(dump.contains("BG_COLOR: #ff0000"));
+        assert!(!dump.contains("HEIGHT"));
+    }
+
+    #[test]
+    fn copy_from_keeps_props() {
+        crate::tests::initialize_test(false);
+        let mut base = Style::default();
+        base.set_width(50);
+        base.set_radius(8);
+
+        let mut style = Style::default();
+        style.set_width(10);
+        style.set_height(20);
+        style.copy_from(&base);
+        drop(base);
+        assert!(matches!(
+            style.get_prop(StyleProp::WIDTH),
+            StyleValues::Num(50)
+        ));
+        assert!(matches!(
+            style.get_prop(StyleProp::RADIUS),
+            StyleValues::Num(8)
+        ));
+        assert!(matches!(
+            style.get_prop(StyleProp::HEIGHT),
+            StyleValues::Num(20)
+        ));
+    }
+
+    #[test]
+    fn try_get_prop_set_and_unset() {
+        crate::tests::initialize_test(false);
+        let mut style = Style::default();
+        style.set_width(0);
+        assert!(matches!(
+            style.try_get_prop(StyleProp::WIDTH),
+            Some(StyleValues::Num(0))
+        ));
+        assert!(style.try_get_prop(StyleProp::HEIGHT).is_none());
+
+        style.set_bg_grad_dir(GradDir::Hor);
+        assert!(matches!(
+            style.try_get_prop(StyleProp::BG_GRAD_DIR),
+            Some(StyleValues::None)
+        ));
+    }
+}
@@ -2,7 +2,7 @@ use crate::{widgets::Widget, Box, LvResult, Obj};
 use core::{
     mem::{self, MaybeUninit},
     num::TryFromIntError,
-    ptr::NonNull,
+    ptr::{self, NonNull},
     time::Duration,
 };
 use cty::c_void;
@@ -14,6 +14,55 @@ pub enum AnimRepeatCount {
     Infinite,
 }
 
+/// The curve an animation's value follows between its start and end values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimPath {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Overshoot,
+    Bounce,
+    Step,
+}
+
+impl From<AnimPath> for lvgl_sys::lv_anim_path_cb_t {
+    fn from(value: AnimPath) -> Self {
+        Some(match value {
+            AnimPath::Linear => lvgl_sys::lv_anim_path_linear,
+            AnimPath::EaseIn => lvgl_sys::lv_anim_path_ease_in,
+            AnimPath::EaseOut => lvgl_sys::lv_anim_path_ease_out,
+            AnimPath::EaseInOut => lvgl_sys::lv_anim_path_ease_in_out,
+            AnimPath::Overshoot => lvgl_sys::lv_anim_path_overshoot,
+            AnimPath::Bounce => lvgl_sys::lv_anim_path_bounce,
+            AnimPath::Step => lvgl_sys::lv_anim_path_step,
+        })
+    }
+}
+
+// The closures an animation calls, shared by every copy LVGL makes of it
+struct AnimCallbacks {
+    exec: *mut c_void,
+    drop_exec: unsafe fn(*mut c_void),
+    ready: *mut c_void,
+    drop_ready: unsafe fn(*mut c_void),
+}
+
+unsafe fn drop_closure<F>(closure: *mut c_void) {
+    drop(Box::from_raw(closure as *mut F));
+}
+
+unsafe fn drop_nothing(_: *mut c_void) {}
+
+impl Drop for AnimCallbacks {
+    fn drop(&mut self) {
+        unsafe {
+            (self.drop_exec)(self.exec);
+            (self.drop_ready)(self.ready);
+        }
+    }
+}
+
 /// An LVGL animation. Equivalent to an `lv_anim_t`.
 pub struct Animation {
     pub(crate) raw: Box<lvgl_sys::lv_anim_t>,
@@ -47,7 +96,12 @@ impl Animation {
             anim.raw.start_value = start;
             anim.raw.current_value = start;
             anim.raw.end_value = end;
-            anim.raw.user_data = Box::<F>::into_raw(Box::new(animator)) as *mut _;
+            anim.raw.user_data = Box::into_raw(Box::new(AnimCallbacks {
+                exec: Box::<F>::into_raw(Box::new(animator)) as *mut _,
+                drop_exec: drop_closure::<F>,
+                ready: ptr::null_mut(),
+                drop_ready: drop_nothing,
+            })) as *mut _;
             anim.raw.var = target as *mut _ as *mut _;
             anim.raw.exec_cb = Some(animator_trampoline::<'a, 'b, T, F>);
 
@@ -55,10 +109,32 @@ impl Animation {
         }
     }
 
-    /// Starts the animation.
+    /// Like [`Animation::new`], but `animator` also receives the progress
+    /// from `start` to `end` as a value in `0.0..=1.0`. Values a path
+    /// overshoots with are clamped to that range.
+    pub fn new_mapped<'a, 'b, T, F>(
+        target: &mut T,
+        duration: Duration,
+        start: i32,
+        end: i32,
+        mut animator: F,
+    ) -> LvResult<Self>
+    where
+        T: Widget<'b>,
+        F: FnMut(&mut Obj, i32, f32) + 'a,
+    {
+        const PROGRESS_MAX: i32 = 1024;
+        Self::new(target, duration, start, end, move |obj, val| {
+            let progress = unsafe { lvgl_sys::lv_map(val, start, end, 0, PROGRESS_MAX) };
+            animator(obj, val, progress as f32 / PROGRESS_MAX as f32)
+        })
+    }
+
+    /// Starts the animation. LVGL runs (and eventually frees) its own copy,
+    /// so changes made to `self` afterwards only apply to later `start`s.
     pub fn start(&mut self) {
         unsafe {
-            self.raw = Box::from_raw(lvgl_sys::lv_anim_start(self.raw.as_mut()));
+            lvgl_sys::lv_anim_start(self.raw.as_mut());
         }
     }
 
@@ -98,24 +174,221 @@ impl Animation {
         }
     }
 
+    /// Sets the path (easing curve) of the animation. Defaults to
+    /// `AnimPath::Linear`.
+    pub fn set_path(&mut self, path: AnimPath) {
+        self.raw.path_cb = path.into();
+    }
+
+    /// Sets a closure to call once the animation has finished, including
+    /// any playback and repeats.
+    pub fn on_ready<'a, F>(&mut self, callback: F)
+    where
+        F: FnMut() + 'a,
+    {
+        unsafe {
+            let callbacks = &mut *(self.raw.user_data as *mut AnimCallbacks);
+            (callbacks.drop_ready)(callbacks.ready);
+            callbacks.ready = Box::<F>::into_raw(Box::new(callback)) as *mut _;
+            callbacks.drop_ready = drop_closure::<F>;
+        }
+        self.raw.ready_cb = Some(ready_trampoline::<'a, F>);
+    }
+
     /// Sets whether changes apply immediately or on the next cycle.
     pub fn set_early_apply(&mut self, apply: bool) {
         (*self.raw).set_early_apply(apply as u8);
     }
 }
 
+/// Builds an [`Animation`] through chainable setters.
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use lvgl::misc::anim::{AnimPath, AnimationBuilder};
+/// use lvgl::widgets::{Btn, CommonWidgetExt};
+/// use lvgl::{Display, DrawBuffer};
+///
+/// # lvgl::init();
+/// # let buffer = DrawBuffer::<{ 240 * 24 }>::default();
+/// # let display = Display::register(buffer, 240, 240, |_| {}).unwrap();
+/// let mut screen = display.get_scr_act().unwrap();
+/// let mut btn = Btn::create(&mut screen).unwrap();
+/// let mut running = AnimationBuilder::new(&mut btn, |obj, val| {
+///     obj.set_pos(val as i16, 10);
+/// })
+/// .unwrap()
+/// .duration(Duration::from_millis(500))
+/// .values(0, 100)
+/// .path(AnimPath::EaseOut)
+/// .start()
+/// .unwrap();
+/// running.stop();
+/// ```
+pub struct AnimationBuilder {
+    anim: Animation,
+    duration: Duration,
+    delay: Duration,
+    playback_time: Duration,
+    playback_delay: Duration,
+    repeat_delay: Duration,
+}
+
+impl AnimationBuilder {
+    /// Starts building an animation of `target`, calling `animator` with
+    /// each new value.
+    pub fn new<'a, 'b, T, F>(target: &mut T, animator: F) -> LvResult<Self>
+    where
+        T: Widget<'b>,
+        F: FnMut(&mut Obj, i32) + 'a,
+    {
+        Ok(Self {
+            anim: Animation::new(target, Duration::ZERO, 0, 0, animator)?,
+            duration: Duration::ZERO,
+            delay: Duration::ZERO,
+            playback_time: Duration::ZERO,
+            playback_delay: Duration::ZERO,
+            repeat_delay: Duration::ZERO,
+        })
+    }
+
+    /// Sets how long the animation takes to go from start to end.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the start and end values.
+    pub fn values(mut self, start: i32, end: i32) -> Self {
+        self.anim.raw.start_value = start;
+        self.anim.raw.current_value = start;
+        self.anim.raw.end_value = end;
+        self
+    }
+
+    /// Sets the delay before starting the animation.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the total playback time.
+    pub fn playback_time(mut self, time: Duration) -> Self {
+        self.playback_time = time;
+        self
+    }
+
+    /// Sets the delay before playback.
+    pub fn playback_delay(mut self, delay: Duration) -> Self {
+        self.playback_delay = delay;
+        self
+    }
+
+    /// Sets the delay before repeating the animation.
+    pub fn repeat_delay(mut self, delay: Duration) -> Self {
+        self.repeat_delay = delay;
+        self
+    }
+
+    /// Sets how many times the animation repeats.
+    pub fn repeat_count(mut self, count: AnimRepeatCount) -> Self {
+        self.anim.set_repeat_count(count);
+        self
+    }
+
+    /// Sets the path (easing curve) of the animation.
+    pub fn path(mut self, path: AnimPath) -> Self {
+        self.anim.set_path(path);
+        self
+    }
+
+    /// Sets whether changes apply immediately or on the next cycle.
+    pub fn early_apply(mut self, apply: bool) -> Self {
+        self.anim.set_early_apply(apply);
+        self
+    }
+
+    /// Sets a closure to call once the animation has finished.
+    pub fn on_ready<'a, F>(mut self, callback: F) -> Self
+    where
+        F: FnMut() + 'a,
+    {
+        self.anim.on_ready(callback);
+        self
+    }
+
+    /// Builds the animation without starting it. Fails if any of the
+    /// durations do not fit LVGL's millisecond counters.
+    pub fn build(mut self) -> Result<Animation, TryFromIntError> {
+        self.anim.raw.time = self.duration.as_millis().try_into()?;
+        self.anim.set_delay(self.delay)?;
+        self.anim.set_playback_time(self.playback_time)?;
+        self.anim.set_playback_delay(self.playback_delay)?;
+        self.anim.set_repeat_delay(self.repeat_delay)?;
+        Ok(self.anim)
+    }
+
+    /// Builds and starts the animation.
+    pub fn start(self) -> Result<RunningAnimation, TryFromIntError> {
+        let mut anim = self.build()?;
+        anim.start();
+        Ok(RunningAnimation { anim })
+    }
+}
+
+/// A handle to an animation that has been started. Dropping the handle
+/// stops the animation and frees its closures.
+pub struct RunningAnimation {
+    anim: Animation,
+}
+
+impl RunningAnimation {
+    /// Whether LVGL is still running the animation.
+    pub fn is_running(&self) -> bool {
+        unsafe { !lvgl_sys::lv_anim_get(self.anim.raw.var, self.anim.raw.exec_cb).is_null() }
+    }
+
+    /// Stops the animation, leaving the target at its current value. Returns
+    /// `false` if it had already finished.
+    pub fn stop(&mut self) -> bool {
+        unsafe { lvgl_sys::lv_anim_del(self.anim.raw.var, self.anim.raw.exec_cb) }
+    }
+
+    /// Starts the animation again from the beginning.
+    pub fn restart(&mut self) {
+        self.anim.start();
+    }
+
+    /// Stops the animation and frees its closures, same as dropping the
+    /// handle.
+    pub fn delete(self) {
+        drop(self)
+    }
+}
+
+impl Drop for RunningAnimation {
+    fn drop(&mut self) {
+        self.stop();
+        unsafe { drop(Box::from_raw(self.anim.raw.user_data as *mut AnimCallbacks)) }
+    }
+}
+
 unsafe extern "C" fn animator_trampoline<'a, 'b, T, F>(obj: *mut c_void, val: i32)
 where
     T: Widget<'b>,
     F: FnMut(&mut Obj, i32) + 'a,
 {
     unsafe {
-        let anim =
-            NonNull::new(lvgl_sys::lv_anim_get(obj, None) as *mut lvgl_sys::lv_anim_t).unwrap();
+        let anim = NonNull::new(lvgl_sys::lv_anim_get(
+            obj,
+            Some(animator_trampoline::<'a, 'b, T, F>),
+        ))
+        .unwrap();
         // yes, we have to do it this way. Casting `obj` directly to `&mut Obj` segfaults
         let obj = (*(obj as *mut T)).raw();
-        if !anim.as_ref().user_data.is_null() {
-            let callback = &mut *(anim.as_ref().user_data as *mut F);
+        let callbacks = anim.as_ref().user_data as *mut AnimCallbacks;
+        if !callbacks.is_null() && !(*callbacks).exec.is_null() {
+            let callback = &mut *((*callbacks).exec as *mut F);
             let mut obj_nondrop = Obj::from_raw(obj).unwrap();
             callback(&mut obj_nondrop, val);
             mem::forget(obj_nondrop)
@@ -123,11 +396,24 @@ where
     }
 }
 
+unsafe extern "C" fn ready_trampoline<'a, F>(anim: *mut lvgl_sys::lv_anim_t)
+where
+    F: FnMut() + 'a,
+{
+    let callbacks = (*anim).user_data as *mut AnimCallbacks;
+    if !callbacks.is_null() && !(*callbacks).ready.is_null() {
+        let callback = &mut *((*callbacks).ready as *mut F);
+        callback();
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::widgets::Btn;
     use crate::Display;
+    use std::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
     fn anim_test() {
@@ -139,4 +425,145 @@ mod test {
             Animation::new(&mut btn, Duration::from_millis(10), 0, 100, |_, _| {}).unwrap();
         anim.start();
     }
+
+    #[test]
+    fn anim_playback() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let seen = values.clone();
+        let mut anim = Animation::new(&mut btn, Duration::from_millis(100), 0, 100, move |_, v| {
+            seen.borrow_mut().push(v)
+        })
+        .unwrap();
+        anim.set_delay(Duration::from_millis(20)).unwrap();
+        anim.set_playback_time(Duration::from_millis(100)).unwrap();
+        anim.set_playback_delay(Duration::from_millis(20)).unwrap();
+        anim.start();
+        for _ in 0..40 {
+            crate::tick_inc(Duration::from_millis(10));
+            crate::task_handler();
+        }
+
+        let values = values.borrow();
+        let peak = values.iter().position(|&v| v == 100).unwrap();
+        assert!(values[..=peak].windows(2).all(|w| w[0] <= w[1]));
+        assert!(values[peak..].windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(values.last(), Some(&0));
+    }
+
+    #[test]
+    fn anim_on_ready() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let ready = Rc::new(RefCell::new(0));
+        let count = ready.clone();
+        let mut anim =
+            Animation::new(&mut btn, Duration::from_millis(50), 0, 100, |_, _| {}).unwrap();
+        anim.on_ready(move || *count.borrow_mut() += 1);
+        anim.start();
+
+        crate::tick_inc(Duration::from_millis(10));
+        crate::task_handler();
+        assert_eq!(*ready.borrow(), 0);
+        for _ in 0..10 {
+            crate::tick_inc(Duration::from_millis(10));
+            crate::task_handler();
+        }
+        assert_eq!(*ready.borrow(), 1);
+    }
+
+    #[test]
+    fn anim_builder_start_stop() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let calls = Rc::new(RefCell::new(0));
+        let count = calls.clone();
+        let mut running = AnimationBuilder::new(&mut btn, move |_, _| *count.borrow_mut() += 1)
+            .unwrap()
+            .duration(Duration::from_millis(1000))
+            .values(0, 100)
+            .path(AnimPath::EaseInOut)
+            .start()
+            .unwrap();
+        for _ in 0..10 {
+            crate::tick_inc(Duration::from_millis(10));
+            crate::task_handler();
+        }
+        assert!(running.is_running());
+        assert!(*calls.borrow() > 0);
+
+        assert!(running.stop());
+        assert!(!running.is_running());
+        let stopped_at = *calls.borrow();
+        for _ in 0..10 {
+            crate::tick_inc(Duration::from_millis(10));
+            crate::task_handler();
+        }
+        assert_eq!(*calls.borrow(), stopped_at);
+
+        running.restart();
+        assert!(running.is_running());
+        running.delete();
+        // The closure, and with it its reference, was freed
+        assert_eq!(Rc::strong_count(&calls), 1);
+    }
+
+    #[test]
+    fn anim_new_mapped_progress() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let progress = Rc::new(RefCell::new(Vec::new()));
+        let seen = progress.clone();
+        let mut anim = Animation::new_mapped(
+            &mut btn,
+            Duration::from_millis(100),
+            50,
+            250,
+            move |_, _, p| seen.borrow_mut().push(p),
+        )
+        .unwrap();
+        anim.start();
+        for _ in 0..20 {
+            crate::tick_inc(Duration::from_millis(10));
+            crate::task_handler();
+        }
+
+        let progress = progress.borrow();
+        assert!(progress.iter().all(|p| (0.0..=1.0).contains(p)));
+        assert!((progress.last().unwrap() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn anim_set_path() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        for path in [
+            AnimPath::Linear,
+            AnimPath::EaseIn,
+            AnimPath::EaseOut,
+            AnimPath::EaseInOut,
+            AnimPath::Overshoot,
+            AnimPath::Bounce,
+            AnimPath::Step,
+        ] {
+            let mut anim =
+                Animation::new(&mut btn, Duration::from_millis(10), 0, 100, |_, _| {}).unwrap();
+            anim.set_path(path);
+            assert_eq!(anim.raw.path_cb, path.into());
+            anim.start();
+        }
+        crate::tick_inc(Duration::from_millis(20));
+        crate::task_handler();
+    }
 }
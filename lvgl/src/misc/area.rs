@@ -1,35 +1,101 @@
 use lvgl_sys::lv_coord_t;
 
-pub static LV_SIZE_CONTENT: u32 = 2001 | lvgl_sys::_LV_COORD_TYPE_SPEC;
+const COORD_TYPE_SPEC: lv_coord_t = lvgl_sys::_LV_COORD_TYPE_SPEC as lv_coord_t;
+const COORD_TYPE_MASK: lv_coord_t = lvgl_sys::_LV_COORD_TYPE_MASK as lv_coord_t;
+const COORD_MAX: lv_coord_t = ((1 << lvgl_sys::_LV_COORD_TYPE_SHIFT) - 1) as lv_coord_t;
 
-pub fn pct(pct: lv_coord_t) -> lv_coord_t {
-    if pct > 0 {
-        pct | unsafe {
-            <u32 as TryInto<lv_coord_t>>::try_into(lvgl_sys::_LV_COORD_TYPE_SPEC).unwrap_unchecked()
-        }
+/// A size that makes an object as large as its content. Equivalent to
+/// `LV_SIZE_CONTENT`.
+pub const LV_SIZE_CONTENT: lv_coord_t = 2001 | COORD_TYPE_SPEC;
+
+/// Converts a percentage of the parent's size into a coordinate. Equivalent
+/// to `LV_PCT()`.
+pub const fn pct(pct: lv_coord_t) -> lv_coord_t {
+    if pct < 0 {
+        (1000 - pct) | COORD_TYPE_SPEC
     } else {
-        (1000 - pct)
-            | unsafe {
-                <u32 as TryInto<lv_coord_t>>::try_into(lvgl_sys::_LV_COORD_TYPE_SPEC)
-                    .unwrap_unchecked()
-            }
+        pct | COORD_TYPE_SPEC
     }
 }
 
-pub fn coord_is_pct(pct: lv_coord_t) -> bool {
-    (pct & unsafe {
-        <u32 as TryInto<lv_coord_t>>::try_into(lvgl_sys::_LV_COORD_TYPE_MASK).unwrap_unchecked()
-    } == unsafe { lvgl_sys::_LV_COORD_TYPE_SPEC.try_into().unwrap_unchecked() })
-        && (pct
-            & !unsafe {
-                <u32 as TryInto<lv_coord_t>>::try_into(lvgl_sys::_LV_COORD_TYPE_MASK)
-                    .unwrap_unchecked()
-            }
-            <= 2000)
+/// Returns a grid track size taking `x` shares of the free space. Equivalent
+/// to `LV_GRID_FR()`.
+pub const fn grid_fr(x: u8) -> lv_coord_t {
+    COORD_MAX - 100 + x as lv_coord_t
+}
+
+/// Returns whether a coordinate holds a percentage made by `pct()`.
+pub const fn coord_is_pct(pct: lv_coord_t) -> bool {
+    (pct & COORD_TYPE_MASK) == COORD_TYPE_SPEC && (pct & !COORD_TYPE_MASK) <= 2000
 }
 
-pub fn coord_get_pct(pct: lv_coord_t) -> lv_coord_t {
-    (pct & !unsafe {
-        <u32 as TryInto<lv_coord_t>>::try_into(lvgl_sys::_LV_COORD_TYPE_MASK).unwrap_unchecked()
-    }) % 1000
+/// Returns the percentage held by a coordinate made by `pct()`.
+pub const fn coord_get_pct(pct: lv_coord_t) -> lv_coord_t {
+    let plain = pct & !COORD_TYPE_MASK;
+    if plain > 1000 {
+        1000 - plain
+    } else {
+        plain
+    }
+}
+
+/// The width or height of an object.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Size {
+    /// A fixed size in pixels, up to `LV_COORD_MAX`.
+    Px(lv_coord_t),
+    /// A percentage of the parent's content size.
+    Pct(u8),
+    /// As large as the object's content.
+    Content,
+}
+
+impl From<Size> for lv_coord_t {
+    fn from(value: Size) -> lv_coord_t {
+        match value {
+            Size::Px(px) => px,
+            Size::Pct(value) => pct(value as lv_coord_t),
+            Size::Content => LV_SIZE_CONTENT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pct_matches_macro() {
+        // `_LV_COORD_TYPE_SHIFT` is 13 without `LV_USE_LARGE_COORD`
+        assert_eq!(pct(0), 1 << 13);
+        assert_eq!(pct(100), 100 | (1 << 13));
+        assert_eq!(pct(-20), 1020 | (1 << 13));
+        for value in [0, 1, 50, 100, 1000, -1, -50, -100] {
+            assert!(coord_is_pct(pct(value)));
+            assert_eq!(coord_get_pct(pct(value)), value);
+        }
+        assert!(!coord_is_pct(100));
+        assert!(!coord_is_pct(LV_SIZE_CONTENT));
+    }
+
+    #[test]
+    fn size_content_and_grid_fr() {
+        assert_eq!(LV_SIZE_CONTENT, 2001 | (1 << 13));
+        assert_eq!(
+            grid_fr(1),
+            lvgl_sys::LV_GRID_TEMPLATE_LAST as lv_coord_t - 99
+        );
+        assert_eq!(grid_fr(3) - grid_fr(1), 2);
+    }
+
+    #[test]
+    fn size_encoding() {
+        assert_eq!(lv_coord_t::from(Size::Px(0)), 0);
+        assert_eq!(lv_coord_t::from(Size::Px(240)), 240);
+        assert_eq!(lv_coord_t::from(Size::Pct(50)), pct(50));
+        assert_eq!(lv_coord_t::from(Size::Pct(100)), 100 | (1 << 13));
+        assert!(coord_is_pct(Size::Pct(0).into()));
+        assert_eq!(lv_coord_t::from(Size::Content), LV_SIZE_CONTENT);
+        assert!(!coord_is_pct(Size::Content.into()));
+    }
 }
@@ -0,0 +1,148 @@
+use crate::{Box, LvError, LvResult};
+use core::ptr::NonNull;
+use core::time::Duration;
+use cty::c_void;
+
+/// A callback LVGL calls periodically from `task_handler()`. Equivalent to
+/// an `lv_timer_t`.
+///
+/// Dropping the handle keeps the timer running; use `delete()` to stop it
+/// for good.
+#[derive(Debug)]
+pub struct Timer {
+    raw: NonNull<lvgl_sys::lv_timer_t>,
+    drop_closure: unsafe fn(*mut c_void),
+}
+
+impl Timer {
+    /// Creates a timer calling `cb` every `period`, starting one period from
+    /// now. Fails with `LvError::InvalidArgument` if the period does not fit
+    /// LVGL's millisecond counter.
+    pub fn new<F>(period: Duration, cb: F) -> LvResult<Self>
+    where
+        F: FnMut() + 'static,
+    {
+        unsafe fn drop_closure<F>(closure: *mut c_void) {
+            drop(Box::from_raw(closure as *mut F));
+        }
+
+        let period = millis(period)?;
+        unsafe {
+            let closure = Box::into_raw(Box::new(cb)) as *mut c_void;
+            match NonNull::new(lvgl_sys::lv_timer_create(
+                Some(timer_trampoline::<F>),
+                period,
+                closure,
+            )) {
+                Some(raw) => Ok(Self {
+                    raw,
+                    drop_closure: drop_closure::<F>,
+                }),
+                None => {
+                    drop_closure::<F>(closure);
+                    Err(LvError::LvOOMemory)
+                }
+            }
+        }
+    }
+
+    /// Stops calling the callback until `resume()`.
+    pub fn pause(&mut self) {
+        unsafe { lvgl_sys::lv_timer_pause(self.raw.as_ptr()) }
+    }
+
+    /// Calls the callback again after `pause()`.
+    pub fn resume(&mut self) {
+        unsafe { lvgl_sys::lv_timer_resume(self.raw.as_ptr()) }
+    }
+
+    /// Sets the time between two calls of the callback. Fails like `new()`
+    /// if the period is too long.
+    pub fn set_period(&mut self, period: Duration) -> LvResult<()> {
+        unsafe { lvgl_sys::lv_timer_set_period(self.raw.as_ptr(), millis(period)?) }
+        Ok(())
+    }
+
+    /// Stops the timer and frees its callback.
+    pub fn delete(self) {
+        unsafe {
+            let closure = self.raw.as_ref().user_data;
+            lvgl_sys::lv_timer_del(self.raw.as_ptr());
+            (self.drop_closure)(closure);
+        }
+    }
+}
+
+fn millis(period: Duration) -> LvResult<u32> {
+    period
+        .as_millis()
+        .try_into()
+        .map_err(|_| LvError::InvalidArgument)
+}
+
+unsafe extern "C" fn timer_trampoline<F>(timer: *mut lvgl_sys::lv_timer_t)
+where
+    F: FnMut(),
+{
+    if let Some(callback) = ((*timer).user_data as *mut F).as_mut() {
+        callback();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn run(ms: u64) {
+        for _ in 0..ms / 5 {
+            crate::tick_inc(Duration::from_millis(5));
+            crate::task_handler();
+        }
+    }
+
+    #[test]
+    fn timer_fires_periodically() {
+        crate::tests::initialize_test(true);
+        let calls = Rc::new(RefCell::new(0));
+        let count = calls.clone();
+        let timer =
+            Timer::new(Duration::from_millis(10), move || *count.borrow_mut() += 1).unwrap();
+
+        run(5);
+        assert_eq!(*calls.borrow(), 0);
+        run(50);
+        assert!((4..=6).contains(&*calls.borrow()), "{}", calls.borrow());
+        timer.delete();
+    }
+
+    #[test]
+    fn timer_pause_and_delete() {
+        crate::tests::initialize_test(true);
+        let calls = Rc::new(RefCell::new(0));
+        let count = calls.clone();
+        let mut timer =
+            Timer::new(Duration::from_millis(10), move || *count.borrow_mut() += 1).unwrap();
+
+        timer.pause();
+        run(50);
+        assert_eq!(*calls.borrow(), 0);
+
+        timer.resume();
+        assert_eq!(
+            timer.set_period(Duration::MAX),
+            Err(LvError::InvalidArgument)
+        );
+        timer.set_period(Duration::from_millis(20)).unwrap();
+        run(50);
+        let fired = *calls.borrow();
+        assert!((2..=3).contains(&fired), "{}", fired);
+
+        timer.delete();
+        run(50);
+        assert_eq!(*calls.borrow(), fired);
+        // The closure, and with it its reference, was freed
+        assert_eq!(Rc::strong_count(&calls), 1);
+    }
+}
@@ -1,21 +1,28 @@
-use crate::{display::DisplayError, widgets::Widget};
+use crate::{display::DisplayError, widgets::Widget, Obj};
 use core::convert::{TryFrom, TryInto};
 #[cfg(feature = "nightly")]
 use core::error::Error;
 use core::fmt;
 use core::ptr::NonNull;
 #[cfg(feature = "embedded_graphics")]
-use embedded_graphics::pixelcolor::{Rgb565, Rgb888};
+use embedded_graphics::pixelcolor::{Rgb565, Rgb888, RgbColor};
 
 pub type LvResult<T> = Result<T, LvError>;
 
 /// Generic LVGL error. All other errors can be coerced into it.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum LvError {
+    /// A pointer or index given to LVGL did not refer to anything valid.
     InvalidReference,
+    /// LVGL or the display it needs was not initialized yet.
     Uninitialized,
+    /// LVGL could not allocate memory, e.g. for a new widget.
     LvOOMemory,
+    /// The resource is already used elsewhere.
     AlreadyInUse,
+    /// An argument is out of the range LVGL accepts, e.g. a buffer that is
+    /// too small.
+    InvalidArgument,
 }
 
 impl fmt::Display for LvError {
@@ -28,6 +35,7 @@ impl fmt::Display for LvError {
                 LvError::Uninitialized => "LVGL uninitialized",
                 LvError::LvOOMemory => "LVGL out of memory",
                 LvError::AlreadyInUse => "Resource already in use",
+                LvError::InvalidArgument => "Invalid argument",
             }
         )
     }
@@ -41,7 +49,8 @@ impl From<DisplayError> for LvError {
         use LvError::*;
         match err {
             DisplayError::NotAvailable => Uninitialized,
-            DisplayError::FailedToRegister => InvalidReference,
+            // Registering only fails when the driver can't be allocated
+            DisplayError::FailedToRegister => LvOOMemory,
             DisplayError::NotRegistered => Uninitialized,
         }
     }
@@ -55,6 +64,7 @@ impl From<LvError> for DisplayError {
             LvError::Uninitialized => NotAvailable,
             LvError::LvOOMemory => FailedToRegister,
             LvError::AlreadyInUse => FailedToRegister,
+            LvError::InvalidArgument => FailedToRegister,
         }
     }
 }
@@ -87,11 +97,98 @@ impl Color {
     pub fn b(&self) -> u8 {
         unsafe { lvgl_sys::_LV_COLOR_GET_B(self.raw) as u8 }
     }
+    /// Returns the red, green, and blue values, scaled to 8 bits each
+    /// whatever the color depth.
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let [b, g, r, _] = unsafe { lvgl_sys::_LV_COLOR_TO32(self.raw) }.to_le_bytes();
+        (r, g, b)
+    }
+    /// Returns the hue (0-359), saturation (0-100), and value (0-100).
+    pub fn to_hsv(&self) -> (u16, u8, u8) {
+        let hsv = unsafe { lvgl_sys::lv_color_to_hsv(self.raw) };
+        (hsv.h, hsv.s, hsv.v)
+    }
+}
+
+/// The colors of LVGL's built-in palette, based on Material Design.
+///
+/// ```
+/// use lvgl::Palette;
+///
+/// lvgl::init();
+/// let border = Palette::Blue.darken(2);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Palette {
+    Red,
+    Pink,
+    Purple,
+    DeepPurple,
+    Indigo,
+    Blue,
+    LightBlue,
+    Cyan,
+    Teal,
+    Green,
+    LightGreen,
+    Lime,
+    Yellow,
+    Amber,
+    Orange,
+    DeepOrange,
+    Brown,
+    BlueGrey,
+    Grey,
+}
+
+impl Palette {
+    /// Returns the main shade of the color.
+    pub fn main(self) -> Color {
+        Color::from_raw(unsafe { lvgl_sys::lv_palette_main(self.into()) })
+    }
+    /// Returns a lighter shade of the color, `level` ranging from 1 to 5.
+    pub fn lighten(self, level: u8) -> Color {
+        Color::from_raw(unsafe { lvgl_sys::lv_palette_lighten(self.into(), level) })
+    }
+    /// Returns a darker shade of the color, `level` ranging from 1 to 4.
+    pub fn darken(self, level: u8) -> Color {
+        Color::from_raw(unsafe { lvgl_sys::lv_palette_darken(self.into(), level) })
+    }
+}
+
+impl From<Palette> for lvgl_sys::lv_palette_t {
+    fn from(value: Palette) -> Self {
+        match value {
+            Palette::Red => lvgl_sys::lv_palette_t_LV_PALETTE_RED,
+            Palette::Pink => lvgl_sys::lv_palette_t_LV_PALETTE_PINK,
+            Palette::Purple => lvgl_sys::lv_palette_t_LV_PALETTE_PURPLE,
+            Palette::DeepPurple => lvgl_sys::lv_palette_t_LV_PALETTE_DEEP_PURPLE,
+            Palette::Indigo => lvgl_sys::lv_palette_t_LV_PALETTE_INDIGO,
+            Palette::Blue => lvgl_sys::lv_palette_t_LV_PALETTE_BLUE,
+            Palette::LightBlue => lvgl_sys::lv_palette_t_LV_PALETTE_LIGHT_BLUE,
+            Palette::Cyan => lvgl_sys::lv_palette_t_LV_PALETTE_CYAN,
+            Palette::Teal => lvgl_sys::lv_palette_t_LV_PALETTE_TEAL,
+            Palette::Green => lvgl_sys::lv_palette_t_LV_PALETTE_GREEN,
+            Palette::LightGreen => lvgl_sys::lv_palette_t_LV_PALETTE_LIGHT_GREEN,
+            Palette::Lime => lvgl_sys::lv_palette_t_LV_PALETTE_LIME,
+            Palette::Yellow => lvgl_sys::lv_palette_t_LV_PALETTE_YELLOW,
+            Palette::Amber => lvgl_sys::lv_palette_t_LV_PALETTE_AMBER,
+            Palette::Orange => lvgl_sys::lv_palette_t_LV_PALETTE_ORANGE,
+            Palette::DeepOrange => lvgl_sys::lv_palette_t_LV_PALETTE_DEEP_ORANGE,
+            Palette::Brown => lvgl_sys::lv_palette_t_LV_PALETTE_BROWN,
+            Palette::BlueGrey => lvgl_sys::lv_palette_t_LV_PALETTE_BLUE_GREY,
+            Palette::Grey => lvgl_sys::lv_palette_t_LV_PALETTE_GREY,
+        }
+    }
 }
 
 #[cfg(feature = "embedded_graphics")]
 impl From<Color> for Rgb888 {
     fn from(color: Color) -> Self {
+        // 16-bit channels have to be scaled up, not just copied
+        if lvgl_sys::LV_COLOR_DEPTH == 16 {
+            return Rgb565::from(color).into();
+        }
         unsafe {
             Rgb888::new(
                 lvgl_sys::_LV_COLOR_GET_R(color.raw) as u8,
@@ -105,6 +202,10 @@ impl From<Color> for Rgb888 {
 #[cfg(feature = "embedded_graphics")]
 impl From<Color> for Rgb565 {
     fn from(color: Color) -> Self {
+        // 32-bit channels have to be scaled down, not just truncated
+        if lvgl_sys::LV_COLOR_DEPTH == 32 {
+            return Rgb888::from(color).into();
+        }
         unsafe {
             Rgb565::new(
                 lvgl_sys::_LV_COLOR_GET_R(color.raw) as u8,
@@ -115,6 +216,20 @@ impl From<Color> for Rgb565 {
     }
 }
 
+#[cfg(feature = "embedded_graphics")]
+impl From<Rgb888> for Color {
+    fn from(color: Rgb888) -> Self {
+        Color::from_rgb((color.r(), color.g(), color.b()))
+    }
+}
+
+#[cfg(feature = "embedded_graphics")]
+impl From<Rgb565> for Color {
+    fn from(color: Rgb565) -> Self {
+        Rgb888::from(color).into()
+    }
+}
+
 impl From<Color> for lvgl_sys::lv_color_t {
     fn from(val: Color) -> Self {
         val.raw
@@ -185,6 +300,9 @@ pub enum Event<T> {
     ///
     DrawPostEnd,
 
+    /// A key was sent to the object, see `EventContext::key()`
+    Key,
+
     /// Called on focus
     Focused,
 
@@ -209,6 +327,7 @@ impl<S> TryFrom<lvgl_sys::lv_event_code_t> for Event<S> {
             lvgl_sys::lv_event_code_t_LV_EVENT_LONG_PRESSED_REPEAT;
         const LV_EVENT_RELEASED: u32 = lvgl_sys::lv_event_code_t_LV_EVENT_RELEASED;
         const LV_EVENT_VALUE_CHANGED: u32 = lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED;
+        const LV_EVENT_KEY: u32 = lvgl_sys::lv_event_code_t_LV_EVENT_KEY;
         const LV_EVENT_DRAW_MAIN: u32 = lvgl_sys::lv_event_code_t_LV_EVENT_DRAW_MAIN;
         const LV_EVENT_DRAW_MAIN_BEGIN: u32 = lvgl_sys::lv_event_code_t_LV_EVENT_DRAW_MAIN_BEGIN;
         const LV_EVENT_DRAW_MAIN_END: u32 = lvgl_sys::lv_event_code_t_LV_EVENT_DRAW_MAIN_END;
@@ -228,6 +347,7 @@ impl<S> TryFrom<lvgl_sys::lv_event_code_t> for Event<S> {
             LV_EVENT_LONG_PRESSED_REPEAT => Ok(Event::LongPressedRepeat),
             LV_EVENT_RELEASED => Ok(Event::Released),
             LV_EVENT_VALUE_CHANGED => Ok(Event::ValueChanged),
+            LV_EVENT_KEY => Ok(Event::Key),
             LV_EVENT_DRAW_MAIN => Ok(Event::DrawMain),
             LV_EVENT_DRAW_MAIN_BEGIN => Ok(Event::DrawMainBegin),
             LV_EVENT_DRAW_MAIN_END => Ok(Event::DrawMainEnd),
@@ -253,6 +373,7 @@ impl<S> From<Event<S>> for lvgl_sys::lv_event_code_t {
             Event::LongPressedRepeat => lvgl_sys::lv_event_code_t_LV_EVENT_LONG_PRESSED_REPEAT,
             Event::Released => lvgl_sys::lv_event_code_t_LV_EVENT_RELEASED,
             Event::ValueChanged => lvgl_sys::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+            Event::Key => lvgl_sys::lv_event_code_t_LV_EVENT_KEY,
             Event::DrawMain => lvgl_sys::lv_event_code_t_LV_EVENT_DRAW_MAIN,
             Event::DrawMainBegin => lvgl_sys::lv_event_code_t_LV_EVENT_DRAW_MAIN_BEGIN,
             Event::DrawMainEnd => lvgl_sys::lv_event_code_t_LV_EVENT_DRAW_MAIN_END,
@@ -276,26 +397,165 @@ pub enum PointerEvent {
     DragThrowBegin,
 }
 
-pub(crate) unsafe extern "C" fn event_callback<'a, T, F>(event: *mut lvgl_sys::lv_event_t)
+/// Details of the event being handled, passed to the callbacks of
+/// `on_event_with_context()`.
+pub struct EventContext {
+    raw: NonNull<lvgl_sys::lv_event_t>,
+}
+
+impl EventContext {
+    /// The object that originally received the event. It differs from the
+    /// object the callback was registered on if the event bubbled up from a
+    /// child.
+    pub fn target(&self) -> Obj<'_> {
+        unsafe {
+            let target = lvgl_sys::lv_event_get_target(self.raw.as_ptr());
+            Obj::from_raw(NonNull::new(target).unwrap()).unwrap()
+        }
+    }
+
+    /// The object the callback was registered on.
+    pub fn current_target(&self) -> Obj<'_> {
+        unsafe {
+            let target = lvgl_sys::lv_event_get_current_target(self.raw.as_ptr());
+            Obj::from_raw(NonNull::new(target).unwrap()).unwrap()
+        }
+    }
+
+    /// The key sent with an `Event::Key`, like `lvgl_sys::LV_KEY_ENTER`, or
+    /// 0 for other events.
+    pub fn key(&self) -> u32 {
+        unsafe { lvgl_sys::lv_event_get_key(self.raw.as_ptr()) }
+    }
+
+    /// The parameter sent with the event, if any.
+    ///
+    /// # Safety
+    ///
+    /// The parameter must point to a `T`. What it points to depends on the
+    /// event, see the LVGL documentation of each event code.
+    pub unsafe fn get_param<T>(&self) -> Option<&T> {
+        (lvgl_sys::lv_event_get_param(self.raw.as_ptr()) as *const T).as_ref()
+    }
+
+    /// Returns the raw LVGL event.
+    pub fn raw(&self) -> NonNull<lvgl_sys::lv_event_t> {
+        self.raw
+    }
+}
+
+/// An event callback registered on an object, returned by `on_event()` and
+/// its variants.
+///
+/// Dropping the handle keeps the callback registered until the object is
+/// deleted, which frees it; use `remove()` to unregister it earlier.
+#[derive(Debug)]
+pub struct EventHandle {
+    obj: NonNull<lvgl_sys::lv_obj_t>,
+    event_cb: unsafe extern "C" fn(*mut lvgl_sys::lv_event_t),
+    free_cb: unsafe extern "C" fn(*mut lvgl_sys::lv_event_t),
+    closure: *mut cty::c_void,
+    drop_closure: unsafe fn(*mut cty::c_void),
+}
+
+impl EventHandle {
+    /// Unregisters the callback, returning whether it was still registered.
+    /// Nothing happens if the object was deleted in the meantime.
+    pub fn remove(self) -> bool {
+        unsafe {
+            if !lvgl_sys::lv_obj_is_valid(self.obj.as_ptr()) {
+                return false;
+            }
+            let removed = lvgl_sys::lv_obj_remove_event_cb_with_user_data(
+                self.obj.as_ptr(),
+                Some(self.event_cb),
+                self.closure,
+            );
+            if removed {
+                lvgl_sys::lv_obj_remove_event_cb_with_user_data(
+                    self.obj.as_ptr(),
+                    Some(self.free_cb),
+                    self.closure,
+                );
+                (self.drop_closure)(self.closure);
+            }
+            removed
+        }
+    }
+}
+
+/// Registers `f` as an event callback of `obj`, for events of kind `code`.
+///
+/// The closure is stored in the event descriptor, so an object can have
+/// several callbacks. It is freed when the object is deleted, after it has
+/// seen the `LV_EVENT_DELETE` itself.
+pub(crate) unsafe fn add_event_cb<'a, T, F>(
+    obj: *mut lvgl_sys::lv_obj_t,
+    code: lvgl_sys::lv_event_code_t,
+    f: F,
+) -> LvResult<EventHandle>
 where
     T: Widget<'a> + Sized,
-    F: FnMut(T, Event<<T as Widget<'a>>::SpecialEvent>),
+    F: FnMut(T, Event<<T as Widget<'a>>::SpecialEvent>, &EventContext) + 'static,
 {
-    let code = (*event).code;
-    let obj = (*event).target;
-    // convert the lv_event_code_t to lvgl-rs Event type
-    if let Ok(code) = code.try_into() {
-        if let Some(obj_ptr) = NonNull::new(obj) {
-            let object = T::from_raw(obj_ptr).unwrap();
-            // get the pointer from the Rust callback closure FnMut provided by users
-            let user_closure = &mut *((*obj).user_data as *mut F);
-            // call user callback closure
-            user_closure(object, code);
-        }
+    unsafe fn drop_closure<F>(closure: *mut cty::c_void) {
+        drop(crate::Box::from_raw(closure as *mut F));
+    }
+
+    unsafe extern "C" fn free_closure<F>(event: *mut lvgl_sys::lv_event_t) {
+        drop_closure::<F>(lvgl_sys::lv_event_get_user_data(event));
+    }
+
+    let Some(obj) = NonNull::new(obj) else {
+        return Err(LvError::InvalidReference);
+    };
+    let closure = crate::Box::into_raw(crate::Box::new(f)) as *mut cty::c_void;
+    let event_cb: unsafe extern "C" fn(*mut lvgl_sys::lv_event_t) =
+        context_event_callback::<'a, T, F>;
+    if lvgl_sys::lv_obj_add_event_cb(obj.as_ptr(), Some(event_cb), code, closure).is_null() {
+        drop_closure::<F>(closure);
+        return Err(LvError::LvOOMemory);
+    }
+    // Registered after the callback, so it still gets to see the deletion
+    if lvgl_sys::lv_obj_add_event_cb(
+        obj.as_ptr(),
+        Some(free_closure::<F>),
+        lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+        closure,
+    )
+    .is_null()
+    {
+        lvgl_sys::lv_obj_remove_event_cb_with_user_data(obj.as_ptr(), Some(event_cb), closure);
+        drop_closure::<F>(closure);
+        return Err(LvError::LvOOMemory);
+    }
+    Ok(EventHandle {
+        obj,
+        event_cb,
+        free_cb: free_closure::<F>,
+        closure,
+        drop_closure: drop_closure::<F>,
+    })
+}
+
+unsafe extern "C" fn context_event_callback<'a, T, F>(event: *mut lvgl_sys::lv_event_t)
+where
+    T: Widget<'a> + Sized,
+    F: FnMut(T, Event<<T as Widget<'a>>::SpecialEvent>, &EventContext),
+{
+    let code = lvgl_sys::lv_event_get_code(event);
+    let obj = lvgl_sys::lv_event_get_current_target(event);
+    if let (Ok(code), Some(obj_ptr), Some(raw)) =
+        (code.try_into(), NonNull::new(obj), NonNull::new(event))
+    {
+        let object = T::from_raw(obj_ptr).unwrap();
+        let user_closure = &mut *(lvgl_sys::lv_event_get_user_data(event) as *mut F);
+        user_closure(object, code, &EventContext { raw });
     }
 }
 
 /// Possible LVGL alignments for widgets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Align {
     Center,
     TopLeft,
@@ -349,6 +609,37 @@ impl From<Align> for u8 {
     }
 }
 
+impl TryFrom<u8> for Align {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value as u32 {
+            lvgl_sys::LV_ALIGN_CENTER => Ok(Align::Center),
+            lvgl_sys::LV_ALIGN_TOP_LEFT => Ok(Align::TopLeft),
+            lvgl_sys::LV_ALIGN_TOP_MID => Ok(Align::TopMid),
+            lvgl_sys::LV_ALIGN_TOP_RIGHT => Ok(Align::TopRight),
+            lvgl_sys::LV_ALIGN_BOTTOM_LEFT => Ok(Align::BottomLeft),
+            lvgl_sys::LV_ALIGN_BOTTOM_MID => Ok(Align::BottomMid),
+            lvgl_sys::LV_ALIGN_BOTTOM_RIGHT => Ok(Align::BottomRight),
+            lvgl_sys::LV_ALIGN_LEFT_MID => Ok(Align::LeftMid),
+            lvgl_sys::LV_ALIGN_RIGHT_MID => Ok(Align::RightMid),
+            lvgl_sys::LV_ALIGN_OUT_TOP_LEFT => Ok(Align::OutTopLeft),
+            lvgl_sys::LV_ALIGN_OUT_TOP_MID => Ok(Align::OutTopMid),
+            lvgl_sys::LV_ALIGN_OUT_TOP_RIGHT => Ok(Align::OutTopRight),
+            lvgl_sys::LV_ALIGN_OUT_BOTTOM_LEFT => Ok(Align::OutBottomLeft),
+            lvgl_sys::LV_ALIGN_OUT_BOTTOM_MID => Ok(Align::OutBottomMid),
+            lvgl_sys::LV_ALIGN_OUT_BOTTOM_RIGHT => Ok(Align::OutBottomRight),
+            lvgl_sys::LV_ALIGN_OUT_LEFT_TOP => Ok(Align::OutLeftTop),
+            lvgl_sys::LV_ALIGN_OUT_LEFT_MID => Ok(Align::OutLeftMid),
+            lvgl_sys::LV_ALIGN_OUT_LEFT_BOTTOM => Ok(Align::OutLeftBottom),
+            lvgl_sys::LV_ALIGN_OUT_RIGHT_TOP => Ok(Align::OutRightTop),
+            lvgl_sys::LV_ALIGN_OUT_RIGHT_MID => Ok(Align::OutRightMid),
+            lvgl_sys::LV_ALIGN_OUT_RIGHT_BOTTOM => Ok(Align::OutRightBottom),
+            _ => Err(()),
+        }
+    }
+}
+
 pub enum TextAlign {
     Auto,
     Center,
@@ -368,21 +659,26 @@ impl From<TextAlign> for u8 {
     }
 }
 
-/// Boolean for determining whether animations are enabled.
-pub enum AnimationState {
-    ON,
-    OFF,
+/// Boolean for determining whether animations are enabled. Equivalent to
+/// an `lv_anim_enable_t`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnimEnable {
+    On,
+    Off,
 }
 
-impl From<AnimationState> for lvgl_sys::lv_anim_enable_t {
-    fn from(anim: AnimationState) -> Self {
+impl From<AnimEnable> for lvgl_sys::lv_anim_enable_t {
+    fn from(anim: AnimEnable) -> Self {
         match anim {
-            AnimationState::ON => lvgl_sys::lv_anim_enable_t_LV_ANIM_ON,
-            AnimationState::OFF => lvgl_sys::lv_anim_enable_t_LV_ANIM_OFF,
+            AnimEnable::On => lvgl_sys::lv_anim_enable_t_LV_ANIM_ON,
+            AnimEnable::Off => lvgl_sys::lv_anim_enable_t_LV_ANIM_OFF,
         }
     }
 }
 
+#[deprecated(note = "renamed to `AnimEnable`")]
+pub type AnimationState = AnimEnable;
+
 #[repr(u32)]
 pub enum LabelLongMode {
     Clip = lvgl_sys::LV_LABEL_LONG_CLIP,
@@ -401,6 +697,10 @@ impl From<LabelLongMode> for u8 {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::widgets::Btn;
+    use crate::{Display, NativeObject};
+    use core::cell::RefCell;
+    use std::rc::Rc;
 
     #[test]
     fn color_properties_accessible() {
@@ -416,4 +716,184 @@ mod test {
             assert_eq!(color.b(), 31);
         }
     }
+
+    #[test]
+    fn display_errors_map_to_lv_errors() {
+        use crate::functions::CoreError;
+
+        let register_failed = DisplayError::from(CoreError::OutOfMemory);
+        assert_eq!(register_failed, DisplayError::FailedToRegister);
+        assert_eq!(LvError::from(register_failed), LvError::LvOOMemory);
+        assert_eq!(
+            LvError::from(DisplayError::from(CoreError::ResourceNotAvailable)),
+            LvError::Uninitialized
+        );
+        assert_eq!(
+            LvError::from(DisplayError::NotRegistered),
+            LvError::Uninitialized
+        );
+        assert_eq!(
+            DisplayError::from(LvError::Uninitialized),
+            DisplayError::NotAvailable
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "embedded_graphics")]
+    fn embedded_graphics_color_round_trip() {
+        for rgb in [
+            Rgb888::RED,
+            Rgb888::GREEN,
+            Rgb888::BLUE,
+            Rgb888::WHITE,
+            Rgb888::BLACK,
+        ] {
+            assert_eq!(Rgb888::from(Color::from(rgb)), rgb);
+        }
+        for rgb in [
+            Rgb565::RED,
+            Rgb565::GREEN,
+            Rgb565::BLUE,
+            Rgb565::WHITE,
+            Rgb565::BLACK,
+        ] {
+            assert_eq!(Rgb565::from(Color::from(rgb)), rgb);
+        }
+    }
+
+    #[test]
+    fn color_to_rgb_and_hsv() {
+        for rgb in [
+            (255, 0, 0),
+            (0, 255, 0),
+            (0, 0, 255),
+            (255, 255, 255),
+            (0, 0, 0),
+        ] {
+            assert_eq!(Color::from_rgb(rgb).to_rgb(), rgb);
+        }
+        assert_eq!(Color::from_rgb((255, 0, 0)).to_hsv(), (0, 100, 100));
+        assert_eq!(Color::from_rgb((0, 0, 255)).to_hsv(), (240, 100, 100));
+        assert_eq!(Color::from_rgb((0, 0, 0)).to_hsv().2, 0);
+    }
+
+    #[test]
+    fn palette_matches_sys() {
+        let red = unsafe { lvgl_sys::lv_palette_main(lvgl_sys::lv_palette_t_LV_PALETTE_RED) };
+        assert_eq!(Palette::Red.main().to_rgb(), Color::from_raw(red).to_rgb());
+
+        let grey =
+            unsafe { lvgl_sys::lv_palette_darken(lvgl_sys::lv_palette_t_LV_PALETTE_GREY, 3) };
+        assert_eq!(
+            Palette::Grey.darken(3).to_rgb(),
+            Color::from_raw(grey).to_rgb()
+        );
+    }
+
+    #[test]
+    fn on_event_filtered_only_fires_for_code() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let log = received.clone();
+        btn.on_event_filtered(Event::Clicked, move |_, event| log.borrow_mut().push(event))
+            .unwrap();
+
+        crate::event_send(&mut btn, Event::Pressed);
+        crate::event_send(&mut btn, Event::Clicked);
+        crate::event_send(&mut btn, Event::Released);
+        crate::event_send(&mut btn, Event::Clicked);
+        assert_eq!(*received.borrow(), [Event::Clicked, Event::Clicked]);
+    }
+
+    #[test]
+    fn on_event_with_context_reads_key() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let btn_raw = btn.raw();
+        let keys = Rc::new(RefCell::new(Vec::new()));
+        let log = keys.clone();
+        btn.on_event_with_context(move |_, event, ctx| {
+            if let Event::Key = event {
+                log.borrow_mut()
+                    .push((ctx.key(), ctx.target().raw() == btn_raw));
+            }
+        })
+        .unwrap();
+
+        let mut key = lvgl_sys::LV_KEY_ENTER;
+        btn.send_event_with_param(Event::Key, &mut key).unwrap();
+        btn.send_event(Event::Clicked).unwrap();
+        assert_eq!(*keys.borrow(), [(lvgl_sys::LV_KEY_ENTER, true)]);
+    }
+
+    #[test]
+    fn event_handle_remove() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let fired = Rc::new(RefCell::new(Vec::new()));
+        let log = fired.clone();
+        let first = btn
+            .on_event(move |_, event| {
+                if let Event::Clicked = event {
+                    log.borrow_mut().push(1)
+                }
+            })
+            .unwrap();
+        let log = fired.clone();
+        btn.on_event_filtered(Event::Clicked, move |_, _| log.borrow_mut().push(2))
+            .unwrap();
+
+        crate::event_send(&mut btn, Event::Clicked);
+        assert_eq!(*fired.borrow(), [1, 2]);
+
+        assert!(first.remove());
+        assert_eq!(Rc::strong_count(&fired), 2);
+        fired.borrow_mut().clear();
+        crate::event_send(&mut btn, Event::Clicked);
+        assert_eq!(*fired.borrow(), [2]);
+    }
+
+    #[test]
+    fn event_closures_freed_on_delete() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = events.clone();
+        btn.on_event(move |_, event| log.borrow_mut().push(event))
+            .unwrap();
+        let log = events.clone();
+        btn.on_event_filtered(Event::Clicked, move |_, event| log.borrow_mut().push(event))
+            .unwrap();
+        assert_eq!(Rc::strong_count(&events), 3);
+
+        // Dropped handles don't free the closures, deleting the object does
+        btn.delete();
+        assert_eq!(Rc::strong_count(&events), 1);
+    }
+
+    #[test]
+    fn send_event_clicked() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btn = Btn::create(&mut screen).unwrap();
+        let clicks = Rc::new(RefCell::new(0));
+        let count = clicks.clone();
+        btn.on_event_filtered(Event::Clicked, move |_, _| *count.borrow_mut() += 1)
+            .unwrap();
+
+        btn.send_event(Event::Clicked).unwrap();
+        btn.send_event(Event::Pressed).unwrap();
+        btn.send_event(Event::Clicked).unwrap();
+        assert_eq!(*clicks.borrow(), 2);
+    }
 }
@@ -1,22 +1,22 @@
-use crate::support::AnimationState;
+use crate::support::AnimEnable;
 use crate::widgets::Bar;
 use crate::NativeObject;
 
 impl Bar<'_> {
-    /*/// Set minimum and the maximum values of the bar
-    pub fn set_range(&mut self, min: i16, max: i16) -> LvResult<()> {
-       unsafe {
-           lvgl_sys::lv_bar_set_range(self.core.raw()?.as_mut(), min, max);
-       }
-       Ok(())
-    }*/
-
-    /*/// Set a new value on the bar
-    //pub fn set_value(&mut self, value: i32, anim: AnimationState) {
-    //    unsafe {
-    //        lvgl_sys::lv_bar_set_value(self.core.raw().as_mut(), value, anim.into());
-    //    }
-    //}*/
+    /// Set a new value on the bar. The value is clamped to the range of the
+    /// bar.
+    pub fn set_value(&mut self, value: i32, anim: AnimEnable) {
+        unsafe {
+            lvgl_sys::lv_bar_set_value(self.core.raw().as_mut(), value, anim.into());
+        }
+    }
+
+    /// Set the start value of the bar. Only used in `BarMode::Range`.
+    pub fn set_start_value(&mut self, value: i32, anim: AnimEnable) {
+        unsafe {
+            lvgl_sys::lv_bar_set_start_value(self.core.raw().as_mut(), value, anim.into());
+        }
+    }
 }
 /*
 /// The different parts, of a bar object.
@@ -37,3 +37,49 @@ impl From<BarPart> for u8 {
     }
 }
 */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::widgets::BarMode;
+    use crate::Display;
+
+    #[test]
+    fn bar_value_clamped_to_range() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut bar = Bar::create(&mut screen).unwrap();
+        bar.set_range(20, 80);
+        bar.set_value(50, AnimEnable::Off);
+        assert_eq!(bar.get_value(), 50);
+        bar.set_value(100, AnimEnable::Off);
+        assert_eq!(bar.get_value(), 80);
+        bar.set_value(0, AnimEnable::Off);
+        assert_eq!(bar.get_value(), 20);
+
+        // Narrowing the range clamps the current value
+        bar.set_value(70, AnimEnable::Off);
+        bar.set_range(20, 60);
+        assert_eq!(bar.get_value(), 60);
+    }
+
+    #[test]
+    fn bar_start_value_in_range_mode() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut bar = Bar::create(&mut screen).unwrap();
+        bar.set_mode(BarMode::Range);
+        bar.set_value(60, AnimEnable::Off);
+        bar.set_start_value(30, AnimEnable::Off);
+        assert_eq!(bar.get_start_value(), 30);
+        assert_eq!(bar.get_value(), 60);
+
+        // The start value can't go past the value, nor below the minimum
+        bar.set_start_value(90, AnimEnable::Off);
+        assert_eq!(bar.get_start_value(), 60);
+        bar.set_start_value(-10, AnimEnable::Off);
+        assert_eq!(bar.get_start_value(), 0);
+    }
+}
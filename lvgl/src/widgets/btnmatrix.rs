@@ -0,0 +1,170 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Btnmatrix;
+use crate::{LvError, LvResult};
+use bitflags::bitflags;
+use core::mem;
+use cstr_core::CStr;
+use cty::{c_char, c_void};
+
+bitflags! {
+    /// Control flags of a single button in a `Btnmatrix`.
+    ///
+    /// The lowest bits hold the relative width of the button, see
+    /// `BtnmatrixCtrl::width()`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct BtnmatrixCtrl: u32 {
+        const HIDDEN = lvgl_sys::LV_BTNMATRIX_CTRL_HIDDEN;
+        const NO_REPEAT = lvgl_sys::LV_BTNMATRIX_CTRL_NO_REPEAT;
+        const DISABLED = lvgl_sys::LV_BTNMATRIX_CTRL_DISABLED;
+        const CHECKABLE = lvgl_sys::LV_BTNMATRIX_CTRL_CHECKABLE;
+        const CHECKED = lvgl_sys::LV_BTNMATRIX_CTRL_CHECKED;
+        const CLICK_TRIG = lvgl_sys::LV_BTNMATRIX_CTRL_CLICK_TRIG;
+        const POPOVER = lvgl_sys::LV_BTNMATRIX_CTRL_POPOVER;
+        const RECOLOR = lvgl_sys::LV_BTNMATRIX_CTRL_RECOLOR;
+        const CUSTOM_1 = lvgl_sys::LV_BTNMATRIX_CTRL_CUSTOM_1;
+        const CUSTOM_2 = lvgl_sys::LV_BTNMATRIX_CTRL_CUSTOM_2;
+    }
+}
+
+impl BtnmatrixCtrl {
+    /// A button `units` wide relative to the others in its row, from 1 to 7.
+    pub fn width(units: u8) -> Self {
+        Self::from_bits_retain(units as u32 & lvgl_sys::_LV_BTNMATRIX_WIDTH)
+    }
+}
+
+impl From<BtnmatrixCtrl> for u16 {
+    fn from(value: BtnmatrixCtrl) -> Self {
+        value.bits() as u16
+    }
+}
+
+impl Btnmatrix<'_> {
+    /// Sets the button labels. A `"\n"` entry starts a new row. LVGL keeps
+    /// pointers to the labels until the button matrix is deleted, which the
+    /// wrapper can't track, so they have to live for the rest of the
+    /// program. The array of pointers itself is managed by the widget.
+    ///
+    /// Control flags are reset to their defaults if the number of buttons
+    /// changes.
+    pub fn set_map(&mut self, map: &[&'static CStr]) -> LvResult<()> {
+        unsafe {
+            let ptrs = lvgl_sys::lv_mem_alloc(
+                ((map.len() + 1) * mem::size_of::<*const c_char>()) as cty::size_t,
+            ) as *mut *const c_char;
+            if ptrs.is_null() {
+                return Err(LvError::LvOOMemory);
+            }
+            for (i, label) in map.iter().enumerate() {
+                ptrs.add(i).write(label.as_ptr());
+            }
+            // LVGL maps end with an empty string
+            ptrs.add(map.len()).write(b"\0".as_ptr() as *const c_char);
+
+            let obj = self.core.raw().as_mut();
+            let old = lvgl_sys::lv_btnmatrix_get_map(obj);
+            lvgl_sys::lv_btnmatrix_set_map(obj, ptrs);
+            lvgl_sys::lv_obj_add_event_cb(
+                obj,
+                Some(free_map),
+                lvgl_sys::lv_event_code_t_LV_EVENT_DELETE,
+                ptrs as *mut c_void,
+            );
+            // Only maps set through here are freed, not LVGL's default one
+            if lvgl_sys::lv_obj_remove_event_cb_with_user_data(
+                obj,
+                Some(free_map),
+                old as *const c_void,
+            ) {
+                lvgl_sys::lv_mem_free(old as *mut c_void);
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the control flags of every button, in the order of the map.
+    /// Fails with `LvError::InvalidArgument` if `ctrl_map` has fewer entries
+    /// than there are buttons.
+    pub fn set_ctrl_map(&mut self, ctrl_map: &[BtnmatrixCtrl]) -> LvResult<()> {
+        unsafe {
+            let obj = self.core.raw().as_mut();
+            let btn_cnt = (*(obj as *mut lvgl_sys::lv_obj_t as *mut lvgl_sys::lv_btnmatrix_t))
+                .btn_cnt as usize;
+            if ctrl_map.len() < btn_cnt {
+                return Err(LvError::InvalidArgument);
+            }
+            let raw = lvgl_sys::lv_mem_alloc((btn_cnt * mem::size_of::<u16>()) as cty::size_t)
+                as *mut u16;
+            if raw.is_null() {
+                return Err(LvError::LvOOMemory);
+            }
+            for (i, ctrl) in ctrl_map.iter().take(btn_cnt).enumerate() {
+                raw.add(i).write((*ctrl).into());
+            }
+            // LVGL copies the flags
+            lvgl_sys::lv_btnmatrix_set_ctrl_map(obj, raw);
+            lvgl_sys::lv_mem_free(raw as *mut c_void);
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn free_map(e: *mut lvgl_sys::lv_event_t) {
+    lvgl_sys::lv_mem_free(lvgl_sys::lv_event_get_user_data(e));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    fn label(bytes: &'static [u8]) -> &'static CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    #[test]
+    fn btnmatrix_set_map() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btnm = Btnmatrix::create(&mut screen).unwrap();
+        let map = [label(b"1\0"), label(b"2\0"), label(b"\n\0"), label(b"3\0")];
+        btnm.set_map(&map).unwrap();
+
+        let raw = unsafe { &*(btnm.raw().as_ptr() as *const lvgl_sys::lv_btnmatrix_t) };
+        assert_eq!((raw.btn_cnt, raw.row_cnt), (3, 2));
+        assert_eq!(btnm.get_btn_text(0), Some(map[0]));
+        assert_eq!(btnm.get_btn_text(2), Some(map[3]));
+
+        // Replacing the map frees the previous pointer array
+        btnm.set_map(&map[..2]).unwrap();
+        let raw = unsafe { &*(btnm.raw().as_ptr() as *const lvgl_sys::lv_btnmatrix_t) };
+        assert_eq!((raw.btn_cnt, raw.row_cnt), (2, 1));
+    }
+
+    #[test]
+    fn btnmatrix_set_ctrl_map() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut btnm = Btnmatrix::create(&mut screen).unwrap();
+        let map = [label(b"A\0"), label(b"B\0"), label(b"C\0")];
+        btnm.set_map(&map).unwrap();
+
+        assert_eq!(
+            btnm.set_ctrl_map(&[BtnmatrixCtrl::DISABLED]),
+            Err(LvError::InvalidArgument)
+        );
+        btnm.set_ctrl_map(&[
+            BtnmatrixCtrl::DISABLED,
+            BtnmatrixCtrl::width(2) | BtnmatrixCtrl::CHECKABLE,
+            BtnmatrixCtrl::HIDDEN,
+        ])
+        .unwrap();
+        assert!(btnm.has_btn_ctrl(0, BtnmatrixCtrl::DISABLED.into()));
+        assert!(btnm.has_btn_ctrl(1, BtnmatrixCtrl::CHECKABLE.into()));
+        assert!(!btnm.has_btn_ctrl(1, BtnmatrixCtrl::DISABLED.into()));
+        assert!(btnm.has_btn_ctrl(2, BtnmatrixCtrl::HIDDEN.into()));
+        assert_eq!(u16::from(BtnmatrixCtrl::width(2)), 2);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Calendar;
+use core::num::TryFromIntError;
+
+/// A date on a `Calendar`. Equivalent to an `lv_calendar_date_t`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct CalendarDate {
+    pub year: u16,
+    /// From 1 to 12.
+    pub month: u8,
+    /// From 1 to 31.
+    pub day: u8,
+}
+
+impl CalendarDate {
+    pub const fn new(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+}
+
+impl From<lvgl_sys::lv_calendar_date_t> for CalendarDate {
+    fn from(value: lvgl_sys::lv_calendar_date_t) -> Self {
+        Self::new(value.year, value.month as u8, value.day as u8)
+    }
+}
+
+impl From<CalendarDate> for lvgl_sys::lv_calendar_date_t {
+    fn from(value: CalendarDate) -> Self {
+        Self {
+            year: value.year,
+            month: value.month as i8,
+            day: value.day as i8,
+        }
+    }
+}
+
+impl Calendar<'_> {
+    /// Highlights `dates`. LVGL keeps pointing to them, so they must be
+    /// `'static`. Fails if there are more than `u16::MAX` dates.
+    pub fn set_highlighted_dates(
+        &mut self,
+        dates: &'static [CalendarDate],
+    ) -> Result<(), TryFromIntError> {
+        // `CalendarDate` has the same layout as `lv_calendar_date_t`, and
+        // LVGL only reads the dates
+        unsafe {
+            lvgl_sys::lv_calendar_set_highlighted_dates(
+                self.core.raw().as_mut(),
+                dates.as_ptr() as *mut lvgl_sys::lv_calendar_date_t,
+                dates.len().try_into()?,
+            )
+        }
+        Ok(())
+    }
+
+    /// Gets the date set with `set_today_date()`.
+    pub fn get_today_date(&self) -> CalendarDate {
+        unsafe { *lvgl_sys::lv_calendar_get_today_date(self.core.raw().as_ptr()) }.into()
+    }
+
+    /// Gets the month being shown. The day is unspecified.
+    pub fn get_showed_date(&self) -> CalendarDate {
+        unsafe { *lvgl_sys::lv_calendar_get_showed_date(self.core.raw().as_ptr()) }.into()
+    }
+
+    /// Gets the date the user pressed last, if any.
+    pub fn get_pressed_date(&self) -> Option<CalendarDate> {
+        let mut date = lvgl_sys::lv_calendar_date_t::default();
+        let res =
+            unsafe { lvgl_sys::lv_calendar_get_pressed_date(self.core.raw().as_ptr(), &mut date) };
+        match res as u32 {
+            lvgl_sys::LV_RES_OK => Some(date.into()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    static HOLIDAYS: [CalendarDate; 2] = [
+        CalendarDate::new(2024, 12, 25),
+        CalendarDate::new(2024, 12, 26),
+    ];
+
+    #[test]
+    fn calendar_dates() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut calendar = Calendar::create(&mut screen).unwrap();
+        calendar.set_today_date(2024, 2, 29);
+        calendar.set_showed_date(2024, 12);
+        assert_eq!(calendar.get_today_date(), CalendarDate::new(2024, 2, 29));
+        let showed = calendar.get_showed_date();
+        assert_eq!((showed.year, showed.month), (2024, 12));
+
+        calendar.set_highlighted_dates(&HOLIDAYS).unwrap();
+        let highlighted = unsafe {
+            let calendar = calendar.raw().as_ptr();
+            (
+                lvgl_sys::lv_calendar_get_highlighted_dates(calendar),
+                lvgl_sys::lv_calendar_get_highlighted_dates_num(calendar),
+            )
+        };
+        assert_eq!(highlighted, (HOLIDAYS.as_ptr() as *mut _, 2));
+        assert_eq!(calendar.get_pressed_date(), None);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::lv_core::obj::NativeObject;
+use crate::style::Opacity;
+use crate::widgets::Canvas;
+use crate::{Color, LvError, LvResult};
+
+/// The pixel formats a canvas buffer can hold.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CanvasColorFormat {
+    /// Colors in the format picked by `LV_COLOR_DEPTH`.
+    TrueColor,
+    /// Like `TrueColor`, with an extra alpha byte per pixel.
+    TrueColorAlpha,
+    /// Like `TrueColor`, with `LV_COLOR_CHROMA_KEY` pixels drawn transparent.
+    TrueColorChromaKeyed,
+    /// Indices into a palette of 2 colors.
+    Indexed1Bit,
+    /// Indices into a palette of 4 colors.
+    Indexed2Bit,
+    /// Indices into a palette of 16 colors.
+    Indexed4Bit,
+    /// Indices into a palette of 256 colors.
+    Indexed8Bit,
+    /// A single color, either drawn or not.
+    Alpha1Bit,
+    /// A single color with 4 levels of opacity.
+    Alpha2Bit,
+    /// A single color with 16 levels of opacity.
+    Alpha4Bit,
+    /// A single color with 256 levels of opacity.
+    Alpha8Bit,
+}
+
+impl CanvasColorFormat {
+    /// Returns the number of bytes a `w` by `h` canvas needs in this
+    /// format, including the palette of indexed formats. Equivalent to the
+    /// `LV_CANVAS_BUF_SIZE_*` macros.
+    pub const fn buffer_size(self, w: u16, h: u16) -> usize {
+        let (w, h) = (w as usize, h as usize);
+        let color_size = lvgl_sys::LV_COLOR_SIZE as usize / 8;
+        match self {
+            CanvasColorFormat::TrueColor | CanvasColorFormat::TrueColorChromaKeyed => {
+                color_size * w * h
+            }
+            CanvasColorFormat::TrueColorAlpha => (color_size + 1) * w * h,
+            CanvasColorFormat::Indexed1Bit => (w / 8 + 1) * h + 4 * 2,
+            CanvasColorFormat::Indexed2Bit => (w / 4 + 1) * h + 4 * 4,
+            CanvasColorFormat::Indexed4Bit => (w / 2 + 1) * h + 4 * 16,
+            CanvasColorFormat::Indexed8Bit => w * h + 4 * 256,
+            CanvasColorFormat::Alpha1Bit => (w / 8 + 1) * h,
+            CanvasColorFormat::Alpha2Bit => (w / 4 + 1) * h,
+            CanvasColorFormat::Alpha4Bit => (w / 2 + 1) * h,
+            CanvasColorFormat::Alpha8Bit => w * h,
+        }
+    }
+}
+
+impl From<CanvasColorFormat> for lvgl_sys::lv_img_cf_t {
+    fn from(value: CanvasColorFormat) -> Self {
+        (match value {
+            CanvasColorFormat::TrueColor => lvgl_sys::LV_IMG_CF_TRUE_COLOR,
+            CanvasColorFormat::TrueColorAlpha => lvgl_sys::LV_IMG_CF_TRUE_COLOR_ALPHA,
+            CanvasColorFormat::TrueColorChromaKeyed => lvgl_sys::LV_IMG_CF_TRUE_COLOR_CHROMA_KEYED,
+            CanvasColorFormat::Indexed1Bit => lvgl_sys::LV_IMG_CF_INDEXED_1BIT,
+            CanvasColorFormat::Indexed2Bit => lvgl_sys::LV_IMG_CF_INDEXED_2BIT,
+            CanvasColorFormat::Indexed4Bit => lvgl_sys::LV_IMG_CF_INDEXED_4BIT,
+            CanvasColorFormat::Indexed8Bit => lvgl_sys::LV_IMG_CF_INDEXED_8BIT,
+            CanvasColorFormat::Alpha1Bit => lvgl_sys::LV_IMG_CF_ALPHA_1BIT,
+            CanvasColorFormat::Alpha2Bit => lvgl_sys::LV_IMG_CF_ALPHA_2BIT,
+            CanvasColorFormat::Alpha4Bit => lvgl_sys::LV_IMG_CF_ALPHA_4BIT,
+            CanvasColorFormat::Alpha8Bit => lvgl_sys::LV_IMG_CF_ALPHA_8BIT,
+        }) as lvgl_sys::lv_img_cf_t
+    }
+}
+
+impl Canvas<'_> {
+    /// Sets the buffer the canvas draws into, which must hold at least
+    /// `format.buffer_size(w, h)` bytes. LVGL keeps drawing into the buffer
+    /// until the canvas is deleted, which the wrapper can't track, so it has
+    /// to live for the rest of the program.
+    pub fn set_buffer(
+        &mut self,
+        buf: &'static mut [u8],
+        w: u16,
+        h: u16,
+        format: CanvasColorFormat,
+    ) -> LvResult<()> {
+        if buf.len() < format.buffer_size(w, h) {
+            return Err(LvError::InvalidArgument);
+        }
+        let w = w.try_into().map_err(|_| LvError::InvalidArgument)?;
+        let h = h.try_into().map_err(|_| LvError::InvalidArgument)?;
+        unsafe {
+            lvgl_sys::lv_canvas_set_buffer(
+                self.core.raw().as_mut(),
+                buf.as_mut_ptr() as *mut _,
+                w,
+                h,
+                format.into(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Fills the whole canvas with a color.
+    pub fn fill_bg(&mut self, color: Color, opa: Opacity) {
+        unsafe {
+            lvgl_sys::lv_canvas_fill_bg(self.core.raw().as_mut(), color.raw, opa.into());
+        }
+    }
+
+    /// Sets the color of a single pixel. Coordinates outside the canvas are
+    /// ignored.
+    pub fn set_px(&mut self, x: i16, y: i16, color: Color) {
+        unsafe {
+            lvgl_sys::lv_canvas_set_px_color(self.core.raw().as_mut(), x, y, color.raw);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    const FORMATS: [CanvasColorFormat; 11] = [
+        CanvasColorFormat::TrueColor,
+        CanvasColorFormat::TrueColorAlpha,
+        CanvasColorFormat::TrueColorChromaKeyed,
+        CanvasColorFormat::Indexed1Bit,
+        CanvasColorFormat::Indexed2Bit,
+        CanvasColorFormat::Indexed4Bit,
+        CanvasColorFormat::Indexed8Bit,
+        CanvasColorFormat::Alpha1Bit,
+        CanvasColorFormat::Alpha2Bit,
+        CanvasColorFormat::Alpha4Bit,
+        CanvasColorFormat::Alpha8Bit,
+    ];
+
+    #[test]
+    fn canvas_buffer_size() {
+        // 16 bit colors, see lv_conf.h
+        assert_eq!(CanvasColorFormat::TrueColor.buffer_size(10, 10), 200);
+        assert_eq!(CanvasColorFormat::TrueColorAlpha.buffer_size(10, 10), 300);
+        assert_eq!(CanvasColorFormat::Indexed2Bit.buffer_size(10, 10), 46);
+        assert_eq!(CanvasColorFormat::Alpha1Bit.buffer_size(10, 10), 20);
+        assert_eq!(CanvasColorFormat::Alpha8Bit.buffer_size(10, 10), 100);
+    }
+
+    #[test]
+    fn canvas_buffer_size_matches_lvgl() {
+        for format in FORMATS {
+            for (w, h) in [(1, 1), (7, 3), (32, 32), (100, 40)] {
+                let lvgl_size =
+                    unsafe { lvgl_sys::lv_img_buf_get_img_size(w as i16, h as i16, format.into()) };
+                assert_eq!(format.buffer_size(w, h), lvgl_size as usize, "{:?}", format);
+            }
+        }
+    }
+
+    #[test]
+    fn canvas_draw() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let buf = Box::leak(Box::new(
+            [0u8; CanvasColorFormat::TrueColor.buffer_size(20, 10)],
+        ));
+        let mut canvas = Canvas::create(&mut screen).unwrap();
+        canvas
+            .set_buffer(buf, 20, 10, CanvasColorFormat::TrueColor)
+            .unwrap();
+
+        let red = Color::from_rgb((255, 0, 0));
+        let blue = Color::from_rgb((0, 0, 255));
+        canvas.fill_bg(red, Opacity::OPA_COVER);
+        canvas.set_px(3, 4, blue);
+        let px = |x, y| unsafe { lvgl_sys::lv_canvas_get_px(canvas.raw().as_ptr(), x, y) };
+        assert_eq!(Color::from_raw(px(0, 0)).to_rgb(), red.to_rgb());
+        assert_eq!(Color::from_raw(px(3, 4)).to_rgb(), blue.to_rgb());
+    }
+
+    #[test]
+    fn canvas_buffer_too_small() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let buf = Box::leak(Box::new([0u8; 16]));
+        let mut canvas = Canvas::create(&mut screen).unwrap();
+        assert_eq!(
+            canvas.set_buffer(buf, 20, 10, CanvasColorFormat::TrueColor),
+            Err(LvError::InvalidArgument)
+        );
+    }
+}
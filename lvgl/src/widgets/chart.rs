@@ -0,0 +1,168 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::{Chart, ChartSeries};
+use crate::{LvError, LvResult};
+use core::ptr;
+
+impl Chart<'_> {
+    /// Makes `series` read its y values from `array` instead of its own
+    /// buffer, and sets the chart's point count to the array's length. LVGL
+    /// keeps reading the array until the chart is deleted, which the wrapper
+    /// can't track, so it has to live for the rest of the program.
+    ///
+    /// If other series read from external arrays too, the point count is
+    /// only ever lowered, as their arrays may be shorter. Fails with
+    /// `LvError::InvalidArgument` for an empty array or one longer than
+    /// `u16::MAX`.
+    pub fn set_ext_y_array(
+        &mut self,
+        series: &ChartSeries,
+        array: &'static mut [i16],
+    ) -> LvResult<()> {
+        let len: u16 = array
+            .len()
+            .try_into()
+            .map_err(|_| LvError::InvalidArgument)?;
+        if len == 0 {
+            return Err(LvError::InvalidArgument);
+        }
+        let count = if self.has_ext_array(Some(series)) {
+            len.min(self.get_point_count())
+        } else {
+            len
+        };
+        unsafe {
+            let obj = self.core.raw().as_mut();
+            lvgl_sys::lv_chart_set_ext_y_array(obj, series.raw.as_ptr(), array.as_mut_ptr());
+            lvgl_sys::lv_chart_set_point_count(obj, count);
+        }
+        Ok(())
+    }
+
+    /// Sets the number of points of every series. LVGL reads that many
+    /// values from external arrays, so raising the count fails with
+    /// `LvError::InvalidArgument` once a series uses one, see
+    /// `set_ext_y_array()`.
+    pub fn set_point_count(&mut self, count: u16) -> LvResult<()> {
+        if count > self.get_point_count() && self.has_ext_array(None) {
+            return Err(LvError::InvalidArgument);
+        }
+        unsafe { lvgl_sys::lv_chart_set_point_count(self.core.raw().as_mut(), count) }
+        Ok(())
+    }
+
+    // Whether a series other than `except` reads from an external array
+    fn has_ext_array(&self, except: Option<&ChartSeries>) -> bool {
+        let obj = self.core.raw().as_ptr();
+        let mut ser = unsafe { lvgl_sys::lv_chart_get_series_next(obj, ptr::null()) };
+        while let Some(series) = unsafe { ser.as_ref() } {
+            let excepted = matches!(except, Some(except) if except.raw.as_ptr() == ser);
+            if !excepted && (series.x_ext_buf_assigned() != 0 || series.y_ext_buf_assigned() != 0) {
+                return true;
+            }
+            ser = unsafe { lvgl_sys::lv_chart_get_series_next(obj, ser) };
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::widgets::{ChartAxis, ChartType};
+    use crate::{Color, Display};
+
+    #[test]
+    fn chart_add_series() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut chart = Chart::create(&mut screen).unwrap();
+        let primary = chart.add_series(Color::from_rgb((255, 0, 0)), ChartAxis::PrimaryY);
+        let secondary = chart.add_series(Color::from_rgb((0, 0, 255)), ChartAxis::SecondaryY);
+        assert!(primary.is_some());
+        assert!(secondary.is_some());
+        assert_ne!(primary, secondary);
+    }
+
+    #[test]
+    fn chart_set_type() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut chart = Chart::create(&mut screen).unwrap();
+        chart.set_type(ChartType::Bar);
+        assert_eq!(chart.get_type(), Some(ChartType::Bar));
+    }
+
+    #[test]
+    fn chart_set_range() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut chart = Chart::create(&mut screen).unwrap();
+        chart.set_range(ChartAxis::PrimaryY, -50, 50);
+        let raw = unsafe { &*(chart.raw().as_ptr() as *const lvgl_sys::lv_chart_t) };
+        assert_eq!((raw.ymin[0], raw.ymax[0]), (-50, 50));
+    }
+
+    #[test]
+    fn chart_set_point_count() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut chart = Chart::create(&mut screen).unwrap();
+        chart.set_point_count(25).unwrap();
+        assert_eq!(chart.get_point_count(), 25);
+    }
+
+    #[test]
+    fn chart_set_ext_y_array() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let points = Box::leak(Box::new([1, 2, 3, 4, 5, 6, 7, 8]));
+        let mut chart = Chart::create(&mut screen).unwrap();
+        let series = chart
+            .add_series(Color::from_rgb((255, 0, 0)), ChartAxis::PrimaryY)
+            .unwrap();
+        chart.set_ext_y_array(&series, points).unwrap();
+        assert_eq!(chart.get_point_count(), 8);
+
+        chart.set_value_by_id(series, 2, 42);
+        let y =
+            unsafe { lvgl_sys::lv_chart_get_y_array(chart.raw().as_ptr(), series.raw.as_ptr()) };
+        assert_eq!(unsafe { *y.add(2) }, 42);
+    }
+
+    #[test]
+    fn chart_ext_y_array_bounds() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut chart = Chart::create(&mut screen).unwrap();
+        let series = chart
+            .add_series(Color::from_rgb((255, 0, 0)), ChartAxis::PrimaryY)
+            .unwrap();
+        let other = chart
+            .add_series(Color::from_rgb((0, 0, 255)), ChartAxis::PrimaryY)
+            .unwrap();
+        assert_eq!(
+            chart.set_ext_y_array(&series, Box::leak(Box::new([0i16; 0]))),
+            Err(LvError::InvalidArgument)
+        );
+
+        chart
+            .set_ext_y_array(&series, Box::leak(Box::new([0; 4])))
+            .unwrap();
+        assert_eq!(chart.get_point_count(), 4);
+        assert_eq!(chart.set_point_count(5), Err(LvError::InvalidArgument));
+        chart.set_point_count(3).unwrap();
+        assert_eq!(chart.get_point_count(), 3);
+
+        // A longer array can't raise the count past the shorter one
+        chart
+            .set_ext_y_array(&other, Box::leak(Box::new([0; 8])))
+            .unwrap();
+        assert_eq!(chart.get_point_count(), 3);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Checkbox;
+use crate::ObjState;
+use cstr_core::CStr;
+
+impl Checkbox<'_> {
+    /// Returns the text of the checkbox.
+    pub fn get_text(&self) -> &CStr {
+        // The text is never null, LVGL sets a default one on creation
+        unsafe { CStr::from_ptr(lvgl_sys::lv_checkbox_get_text(self.core.raw().as_ptr())) }
+    }
+
+    /// Checks or unchecks the checkbox.
+    pub fn set_checked(&mut self, checked: bool) {
+        if checked {
+            self.add_state(ObjState::CHECKED);
+        } else {
+            self.clear_state(ObjState::CHECKED);
+        }
+    }
+
+    /// Returns whether the checkbox is checked.
+    pub fn is_checked(&self) -> bool {
+        self.has_state(ObjState::CHECKED)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    #[test]
+    fn checkbox_text() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut checkbox = Checkbox::create(&mut screen).unwrap();
+        let text = CStr::from_bytes_with_nul(b"Remember me\0").unwrap();
+        checkbox.set_text(text);
+        assert_eq!(checkbox.get_text(), text);
+    }
+
+    #[test]
+    fn checkbox_set_checked() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut checkbox = Checkbox::create(&mut screen).unwrap();
+        assert!(!checkbox.is_checked());
+        checkbox.set_checked(true);
+        assert!(checkbox.is_checked());
+        checkbox.set_checked(false);
+        assert!(!checkbox.is_checked());
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(feature = "alloc")]
+mod alloc_imp {
+    use crate::widgets::Dropdown;
+    use alloc::string::String;
+
+    impl Dropdown<'_> {
+        /// Returns the text of the selected option.
+        pub fn get_selected_text(&self) -> String {
+            crate::widgets::get_selected_text(self, lvgl_sys::lv_dropdown_get_selected_str)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::widgets::Dropdown;
+    use crate::Display;
+    use cstr_core::CStr;
+
+    #[test]
+    fn dropdown_options_and_selection() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut dropdown = Dropdown::create(&mut screen).unwrap();
+        dropdown.set_options(CStr::from_bytes_with_nul(b"Apple\nBanana\nCherry\0").unwrap());
+        assert_eq!(dropdown.get_option_cnt(), 3);
+
+        dropdown.add_option(CStr::from_bytes_with_nul(b"Avocado\0").unwrap(), 1);
+        assert_eq!(dropdown.get_option_cnt(), 4);
+        assert_eq!(
+            dropdown.get_options(),
+            CStr::from_bytes_with_nul(b"Apple\nAvocado\nBanana\nCherry\0").ok()
+        );
+
+        dropdown.set_selected(2);
+        assert_eq!(dropdown.get_selected(), 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dropdown_get_selected_text() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut dropdown = Dropdown::create(&mut screen).unwrap();
+        let long = "An option longer than the initial buffer of 32 bytes";
+        let options = alloc::format!("Short\n{}\0", long);
+        dropdown.set_options(CStr::from_bytes_with_nul(options.as_bytes()).unwrap());
+
+        assert_eq!(dropdown.get_selected_text(), "Short");
+        dropdown.set_selected(1);
+        assert_eq!(dropdown.get_selected_text(), long);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::lv_core::obj::NativeObject;
+use crate::style::ImageSrc;
+use crate::widgets::Img;
+
+impl Img<'_> {
+    /// Sets the image to show. Symbol and file sources are copied by LVGL,
+    /// while descriptors are used in place.
+    pub fn set_src(&mut self, src: ImageSrc) {
+        unsafe { lvgl_sys::lv_img_set_src(self.core.raw().as_mut(), src.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+    use cstr_core::CStr;
+
+    #[test]
+    fn img_symbol_and_angle() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut img = Img::create(&mut screen).unwrap();
+        let symbol = CStr::from_bytes_with_nul(lvgl_sys::LV_SYMBOL_OK).unwrap();
+        img.set_src(ImageSrc::Symbol(symbol));
+        let src = unsafe { lvgl_sys::lv_img_get_src(img.raw().as_ptr()) };
+        assert_eq!(
+            unsafe { lvgl_sys::lv_img_src_get_type(src) },
+            lvgl_sys::LV_IMG_SRC_SYMBOL as lvgl_sys::lv_img_src_t
+        );
+        assert_eq!(unsafe { CStr::from_ptr(src as *const _) }, symbol);
+
+        img.set_angle(450);
+        img.set_zoom(512);
+        img.set_pivot(0, 0);
+        assert_eq!(img.get_angle(), 450);
+        assert_eq!(img.get_zoom(), 512);
+    }
+}
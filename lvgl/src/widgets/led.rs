@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test {
+    use crate::lv_core::obj::NativeObject;
+    use crate::widgets::Led;
+    use crate::{Color, Display};
+
+    fn brightness(led: &Led) -> u8 {
+        unsafe { lvgl_sys::lv_led_get_brightness(led.raw().as_ptr()) }
+    }
+
+    #[test]
+    fn led_brightness() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut led = Led::create(&mut screen).unwrap();
+        led.set_color(Color::from_rgb((255, 0, 0)));
+
+        led.set_brightness(150);
+        assert_eq!(brightness(&led), 150);
+        // Brightness is kept between LV_LED_BRIGHT_MIN and LV_LED_BRIGHT_MAX
+        led.set_brightness(10);
+        assert_eq!(brightness(&led), lvgl_sys::LV_LED_BRIGHT_MIN as u8);
+
+        led.on();
+        assert_eq!(brightness(&led), lvgl_sys::LV_LED_BRIGHT_MAX as u8);
+        led.toggle();
+        assert_eq!(brightness(&led), lvgl_sys::LV_LED_BRIGHT_MIN as u8);
+        led.toggle();
+        assert_eq!(brightness(&led), lvgl_sys::LV_LED_BRIGHT_MAX as u8);
+        led.off();
+        assert_eq!(brightness(&led), lvgl_sys::LV_LED_BRIGHT_MIN as u8);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::{Btn, List, Widget};
+use crate::{LvError, LvResult, Obj};
+use core::ptr::{self, NonNull};
+use cstr_core::CStr;
+
+impl<'a> List<'a> {
+    /// Adds a text label to the list, usually as a section title.
+    pub fn add_text(&mut self, text: &CStr) -> LvResult<Obj<'a>> {
+        unsafe {
+            let ptr = lvgl_sys::lv_list_add_text(self.core.raw().as_mut(), text.as_ptr());
+            NonNull::new(ptr)
+                .and_then(|raw| Obj::from_raw(raw))
+                .ok_or(LvError::LvOOMemory)
+        }
+    }
+
+    /// Adds a button to the list, with a text and an optional icon such as
+    /// one of the `LV_SYMBOL_*` strings.
+    pub fn add_btn(&mut self, icon: Option<&CStr>, text: &CStr) -> LvResult<Btn<'a>> {
+        unsafe {
+            let ptr = lvgl_sys::lv_list_add_btn(
+                self.core.raw().as_mut(),
+                icon.map_or(ptr::null(), |icon| icon.as_ptr() as *const _),
+                text.as_ptr(),
+            );
+            NonNull::new(ptr)
+                .and_then(|raw| Btn::from_raw(raw))
+                .ok_or(LvError::LvOOMemory)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::widgets::CommonWidgetExt;
+    use crate::{Display, Event};
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    fn text(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    #[test]
+    fn list_add_text_and_btn() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut list = List::create(&mut screen).unwrap();
+        let title = list.add_text(text(b"Files\0")).unwrap();
+        let mut btn = list
+            .add_btn(Some(text(lvgl_sys::LV_SYMBOL_FILE)), text(b"Open\0"))
+            .unwrap();
+        list.add_btn(None, text(b"Close\0")).unwrap();
+
+        assert_eq!(
+            unsafe { lvgl_sys::lv_obj_get_child_cnt(list.raw().as_ptr()) },
+            3
+        );
+        assert_eq!(title.get_parent().map(|p| p.raw()), Some(list.raw()));
+        let btn_text = unsafe {
+            CStr::from_ptr(lvgl_sys::lv_list_get_btn_text(
+                list.raw().as_mut(),
+                btn.raw().as_mut(),
+            ))
+        };
+        assert_eq!(btn_text, text(b"Open\0"));
+
+        // The returned button takes event handlers like any other
+        let clicked = Rc::new(Cell::new(false));
+        let seen = clicked.clone();
+        btn.on_event(move |_, event| {
+            if let Event::Clicked = event {
+                seen.set(true);
+            }
+        })
+        .unwrap();
+        crate::event_send(&mut btn, Event::Clicked);
+        assert!(clicked.get());
+    }
+}
@@ -15,3 +15,40 @@ impl From<MeterPart> for u8 {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::widgets::Meter;
+    use crate::{Color, Display};
+
+    #[test]
+    fn meter_add_scale() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut meter = Meter::create(&mut screen).unwrap();
+        let scale = meter.add_scale().unwrap();
+        meter.set_scale_range(scale, 10, 90, 270, 135);
+        let raw = unsafe { scale.raw().as_ref() };
+        assert_eq!((raw.min, raw.max), (10, 90));
+        assert_eq!((raw.angle_range, raw.rotation), (270, 135));
+    }
+
+    #[test]
+    fn meter_needle_value() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut meter = Meter::create(&mut screen).unwrap();
+        let scale = meter.add_scale().unwrap();
+        let needle = meter
+            .add_needle_line(scale, 4, Color::from_rgb((0, 0, 255)), -10)
+            .unwrap();
+        assert_eq!(unsafe { needle.raw().as_ref() }.scale, scale.raw().as_ptr());
+
+        meter.set_indicator_value(needle, 42);
+        let raw = unsafe { needle.raw().as_ref() };
+        assert_eq!((raw.start_value, raw.end_value), (42, 42));
+        crate::task_handler();
+    }
+}
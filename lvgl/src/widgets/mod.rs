@@ -7,19 +7,75 @@
 
 mod arc;
 mod bar;
+mod btnmatrix;
+mod calendar;
+mod canvas;
+mod chart;
+mod checkbox;
+mod dropdown;
+mod img;
 mod keyboard;
 mod label;
+mod led;
+mod list;
 mod meter;
+mod roller;
 mod slider;
+mod spinbox;
+mod spinner;
+mod switch;
 mod table;
+mod tabview;
+mod textarea;
 
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
 
 use crate::NativeObject;
 pub use arc::*;
 pub use bar::*;
+pub use btnmatrix::*;
+pub use calendar::*;
+pub use canvas::*;
+pub use chart::*;
+pub use checkbox::*;
+pub use dropdown::*;
+pub use img::*;
 pub use keyboard::*;
 pub use label::*;
+pub use list::*;
 pub use meter::*;
+pub use roller::*;
 pub use slider::*;
+pub use spinbox::*;
+pub use spinner::*;
+pub use switch::*;
 pub use table::*;
+pub use tabview::*;
+pub use textarea::*;
+
+/// Reads the selected option of `obj` through `getter`, one of the LVGL
+/// functions copying it into a buffer of a given size. The buffer grows
+/// until the whole option fits.
+#[cfg(feature = "alloc")]
+pub(crate) fn get_selected_text(
+    obj: &impl NativeObject,
+    getter: unsafe extern "C" fn(*const lvgl_sys::lv_obj_t, *mut cty::c_char, u32),
+) -> alloc::string::String {
+    let mut buf = alloc::vec![0u8; 32];
+    loop {
+        unsafe {
+            getter(
+                obj.raw().as_ptr(),
+                buf.as_mut_ptr() as *mut cty::c_char,
+                buf.len() as u32,
+            );
+        }
+        let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+        // LVGL truncates to one byte less than the buffer
+        if len + 1 < buf.len() {
+            buf.truncate(len);
+            return alloc::string::String::from_utf8_lossy(&buf).into_owned();
+        }
+        buf.resize(buf.len() * 2, 0);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Roller;
+use crate::AnimEnable;
+
+impl Roller<'_> {
+    /// Selects an option. In `RollerMode::Infinite` the roller scrolls the
+    /// shortest way to it, wrapping around the ends.
+    pub fn set_selected(&mut self, sel_opt: u16, anim: AnimEnable) {
+        unsafe {
+            lvgl_sys::lv_roller_set_selected(self.core.raw().as_mut(), sel_opt, anim.into());
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_imp {
+    use crate::widgets::Roller;
+    use alloc::string::String;
+
+    impl Roller<'_> {
+        /// Returns the text of the selected option.
+        pub fn get_selected_str(&self) -> String {
+            crate::widgets::get_selected_text(self, lvgl_sys::lv_roller_get_selected_str)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::widgets::RollerMode;
+    use crate::Display;
+    use cstr_core::CStr;
+
+    fn raw_selected(roller: &Roller) -> u16 {
+        unsafe { (*(roller.raw().as_ptr() as *const lvgl_sys::lv_roller_t)).sel_opt_id }
+    }
+
+    #[test]
+    fn roller_normal_mode_clamps() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut roller = Roller::create(&mut screen).unwrap();
+        roller.set_options(
+            CStr::from_bytes_with_nul(b"One\nTwo\nThree\0").unwrap(),
+            RollerMode::Normal,
+        );
+        assert_eq!(roller.get_option_cnt(), 3);
+
+        roller.set_selected(1, AnimEnable::Off);
+        assert_eq!(roller.get_selected(), 1);
+        roller.set_selected(5, AnimEnable::Off);
+        assert_eq!(roller.get_selected(), 2);
+        roller.set_selected(0, AnimEnable::Off);
+        assert_eq!(raw_selected(&roller), 0);
+    }
+
+    #[test]
+    fn roller_infinite_mode_wraps() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut roller = Roller::create(&mut screen).unwrap();
+        roller.set_options(
+            CStr::from_bytes_with_nul(b"One\nTwo\nThree\0").unwrap(),
+            RollerMode::Infinite,
+        );
+        assert_eq!(roller.get_option_cnt(), 3);
+
+        roller.set_selected(2, AnimEnable::Off);
+        assert_eq!(roller.get_selected(), 2);
+        let last = raw_selected(&roller);
+
+        // From the last option, the first one is reached by moving forward
+        roller.set_selected(0, AnimEnable::Off);
+        assert_eq!(roller.get_selected(), 0);
+        assert_eq!(raw_selected(&roller), last + 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn roller_get_selected_str() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut roller = Roller::create(&mut screen).unwrap();
+        roller.set_options(
+            CStr::from_bytes_with_nul(b"One\nTwo\nThree\0").unwrap(),
+            RollerMode::Infinite,
+        );
+        roller.set_selected(1, AnimEnable::Off);
+        assert_eq!(roller.get_selected_str(), "Two");
+    }
+}
@@ -1,15 +1,61 @@
 use crate::lv_core::obj::NativeObject;
-use crate::widgets::Slider;
-use crate::AnimationState;
+use crate::widgets::{Slider, SliderMode};
+use crate::AnimEnable;
 
 impl Slider<'_> {
     /// Set a new value on the slider
-    pub fn set_value(&self, value: i32, anim: AnimationState) {
-        unsafe { lvgl_sys::lv_bar_set_value(self.core.raw().as_ptr(), value, anim.into()) }
+    pub fn set_value(&mut self, value: i32, anim: AnimEnable) {
+        unsafe { lvgl_sys::lv_bar_set_value(self.core.raw().as_mut(), value, anim.into()) }
     }
 
     /// Gets the current value of the slider
     pub fn get_value(&self) -> i32 {
         unsafe { lvgl_sys::lv_bar_get_value(self.core.raw().as_ptr()) }
     }
+
+    /// Sets the minimum and maximum values of the slider. The current value
+    /// is clamped to the new range.
+    pub fn set_range(&mut self, min: i32, max: i32) {
+        unsafe { lvgl_sys::lv_bar_set_range(self.core.raw().as_mut(), min, max) }
+    }
+
+    /// Sets whether the slider is drawn from the minimum, from zero, or
+    /// between a left and a right knob.
+    pub fn set_mode(&mut self, mode: SliderMode) {
+        unsafe { lvgl_sys::lv_bar_set_mode(self.core.raw().as_mut(), mode.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    #[test]
+    fn slider_set_value() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut slider = Slider::create(&mut screen).unwrap();
+        slider.set_value(42, AnimEnable::Off);
+        assert_eq!(slider.get_value(), 42);
+    }
+
+    #[test]
+    fn slider_set_range_and_mode() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut slider = Slider::create(&mut screen).unwrap();
+        slider.set_range(-10, 10);
+        slider.set_mode(SliderMode::Symmetrical);
+        slider.set_value(50, AnimEnable::Off);
+        assert_eq!(slider.get_value(), 10);
+        slider.set_value(-50, AnimEnable::Off);
+        assert_eq!(slider.get_value(), -10);
+        assert_eq!(
+            unsafe { lvgl_sys::lv_bar_get_mode(slider.raw().as_mut()) },
+            lvgl_sys::lv_slider_mode_t::from(SliderMode::Symmetrical)
+        );
+    }
 }
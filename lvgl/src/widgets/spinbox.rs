@@ -0,0 +1,40 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Spinbox;
+
+impl Spinbox<'_> {
+    /// Gets the current value of the spinbox.
+    pub fn get_value(&self) -> i32 {
+        unsafe { lvgl_sys::lv_spinbox_get_value(self.core.raw().as_ptr()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    #[test]
+    fn spinbox_steps_within_range() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut spinbox = Spinbox::create(&mut screen).unwrap();
+        spinbox.set_range(-10, 10);
+        spinbox.set_step(4);
+        spinbox.set_value(5);
+        assert_eq!(spinbox.get_value(), 5);
+
+        spinbox.increment();
+        assert_eq!(spinbox.get_value(), 9);
+        // Stepping past the maximum stops at it
+        spinbox.increment();
+        assert_eq!(spinbox.get_value(), 10);
+        spinbox.decrement();
+        assert_eq!(spinbox.get_value(), 6);
+
+        spinbox.set_value(50);
+        assert_eq!(spinbox.get_value(), 10);
+        spinbox.set_value(-50);
+        assert_eq!(spinbox.get_value(), -10);
+    }
+}
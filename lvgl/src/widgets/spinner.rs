@@ -0,0 +1,60 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Widget;
+use crate::{LvError, LvResult, Obj};
+use core::ptr::NonNull;
+use core::time::Duration;
+
+define_object!(Spinner);
+
+impl<'a> Spinner<'a> {
+    /// Creates a spinner whose arc, `arc_angle` degrees long, takes `speed`
+    /// for a full turn. A `speed` over `u32::MAX` milliseconds is an
+    /// `LvError::InvalidArgument`.
+    pub fn create(
+        parent: &mut impl NativeObject,
+        speed: Duration,
+        arc_angle: u16,
+    ) -> LvResult<Self> {
+        if !crate::is_init() {
+            return Err(LvError::Uninitialized);
+        }
+        let speed = speed
+            .as_millis()
+            .try_into()
+            .map_err(|_| LvError::InvalidArgument)?;
+        unsafe {
+            let ptr = lvgl_sys::lv_spinner_create(parent.raw().as_mut(), speed, arc_angle.into());
+            if let Some(raw) = NonNull::new(ptr) {
+                let core = <Obj as Widget>::from_raw(raw).unwrap();
+                Ok(Self { core })
+            } else {
+                Err(LvError::LvOOMemory)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    #[test]
+    fn spinner_create() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let spinner = Spinner::create(&mut screen, Duration::from_millis(1000), 60).unwrap();
+        unsafe {
+            assert!(lvgl_sys::lv_obj_is_valid(spinner.raw().as_ptr()));
+            assert!(lvgl_sys::lv_obj_check_type(
+                spinner.raw().as_ptr(),
+                &lvgl_sys::lv_spinner_class
+            ));
+        }
+        assert!(matches!(
+            Spinner::create(&mut screen, Duration::from_secs(u64::MAX), 60),
+            Err(LvError::InvalidArgument)
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Switch;
+use crate::ObjState;
+
+impl Switch<'_> {
+    /// Turns the switch on.
+    pub fn on(&mut self) {
+        self.add_state(ObjState::CHECKED);
+    }
+
+    /// Turns the switch off.
+    pub fn off(&mut self) {
+        self.clear_state(ObjState::CHECKED);
+    }
+
+    /// Returns whether the switch is on.
+    pub fn is_on(&self) -> bool {
+        self.has_state(ObjState::CHECKED)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    #[test]
+    fn switch_toggle() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut switch = Switch::create(&mut screen).unwrap();
+        assert!(!switch.is_on());
+        switch.on();
+        assert!(switch.is_on());
+        switch.on();
+        assert!(switch.is_on());
+        switch.off();
+        assert!(!switch.is_on());
+    }
+}
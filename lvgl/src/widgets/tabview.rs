@@ -0,0 +1,108 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Widget;
+use crate::{AnimEnable, LvError, LvResult, Obj};
+use core::ptr::NonNull;
+use cstr_core::CStr;
+
+define_object!(Tabview);
+
+/// The side of a `Tabview` its tab buttons are placed on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TabPosition {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl From<TabPosition> for lvgl_sys::lv_dir_t {
+    fn from(value: TabPosition) -> Self {
+        (match value {
+            TabPosition::Top => lvgl_sys::LV_DIR_TOP,
+            TabPosition::Bottom => lvgl_sys::LV_DIR_BOTTOM,
+            TabPosition::Left => lvgl_sys::LV_DIR_LEFT,
+            TabPosition::Right => lvgl_sys::LV_DIR_RIGHT,
+        }) as lvgl_sys::lv_dir_t
+    }
+}
+
+impl<'a> Tabview<'a> {
+    /// Creates a tab view with its tab buttons on the `tab_pos` side, taking
+    /// `tab_size` pixels.
+    pub fn create(
+        parent: &mut impl NativeObject,
+        tab_pos: TabPosition,
+        tab_size: i16,
+    ) -> LvResult<Self> {
+        if !crate::is_init() {
+            return Err(LvError::Uninitialized);
+        }
+        unsafe {
+            let ptr = lvgl_sys::lv_tabview_create(parent.raw().as_mut(), tab_pos.into(), tab_size);
+            if let Some(raw) = NonNull::new(ptr) {
+                let core = <Obj as Widget>::from_raw(raw).unwrap();
+                Ok(Self { core })
+            } else {
+                Err(LvError::LvOOMemory)
+            }
+        }
+    }
+
+    /// Adds a tab, returning its content container to place widgets in.
+    pub fn add_tab(&mut self, name: &CStr) -> LvResult<Obj<'a>> {
+        unsafe {
+            let ptr = lvgl_sys::lv_tabview_add_tab(self.core.raw().as_mut(), name.as_ptr());
+            NonNull::new(ptr)
+                .and_then(|raw| Obj::from_raw(raw))
+                .ok_or(LvError::LvOOMemory)
+        }
+    }
+
+    /// Shows the tab with the given index, or the last one if it is out of
+    /// range.
+    pub fn set_active(&mut self, id: u16, anim: AnimEnable) {
+        unsafe {
+            lvgl_sys::lv_tabview_set_act(self.core.raw().as_mut(), id as u32, anim.into());
+        }
+    }
+
+    /// Returns the index of the tab being shown.
+    pub fn get_tab_active(&self) -> u16 {
+        unsafe { lvgl_sys::lv_tabview_get_tab_act(self.core.raw().as_ptr()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::widgets::Label;
+    use crate::Display;
+
+    #[test]
+    fn tabview_switch_tabs() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut tabview = Tabview::create(&mut screen, TabPosition::Top, 40).unwrap();
+        let mut first = tabview
+            .add_tab(CStr::from_bytes_with_nul(b"First\0").unwrap())
+            .unwrap();
+        let second = tabview
+            .add_tab(CStr::from_bytes_with_nul(b"Second\0").unwrap())
+            .unwrap();
+        Label::create(&mut first).unwrap();
+        assert_eq!(
+            unsafe { lvgl_sys::lv_obj_get_child_cnt(first.raw().as_ptr()) },
+            1
+        );
+        assert_ne!(first.raw(), second.raw());
+        assert_eq!(tabview.get_tab_active(), 0);
+
+        tabview.set_active(1, AnimEnable::Off);
+        assert_eq!(tabview.get_tab_active(), 1);
+        tabview.set_active(5, AnimEnable::Off);
+        assert_eq!(tabview.get_tab_active(), 1);
+        tabview.set_active(0, AnimEnable::On);
+        assert_eq!(tabview.get_tab_active(), 0);
+    }
+}
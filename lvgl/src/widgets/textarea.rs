@@ -0,0 +1,53 @@
+use crate::lv_core::obj::NativeObject;
+use crate::widgets::Textarea;
+use cstr_core::CStr;
+
+impl Textarea<'_> {
+    /// Returns the text of the text area. In password mode this is the
+    /// actual text, not the bullets shown on screen.
+    pub fn get_text(&self) -> &CStr {
+        unsafe { CStr::from_ptr(lvgl_sys::lv_textarea_get_text(self.core.raw().as_ptr())) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Display;
+
+    fn text(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    #[test]
+    fn textarea_add_text() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut textarea = Textarea::create(&mut screen).unwrap();
+        textarea.set_text(text(b"Hello\0"));
+        textarea.add_text(text(b", world\0"));
+        assert_eq!(textarea.get_text(), text(b"Hello, world\0"));
+
+        textarea.set_placeholder_text(text(b"Name\0"));
+        assert_eq!(textarea.get_placeholder_text(), Some(text(b"Name\0")));
+    }
+
+    #[test]
+    fn textarea_one_line_and_password_mode() {
+        crate::tests::initialize_test(true);
+        let display = Display::default();
+        let mut screen = display.get_scr_act().unwrap();
+        let mut textarea = Textarea::create(&mut screen).unwrap();
+        assert!(!textarea.get_one_line());
+        textarea.set_one_line(true);
+        assert!(textarea.get_one_line());
+        textarea.set_one_line(false);
+        assert!(!textarea.get_one_line());
+
+        textarea.set_password_mode(true);
+        textarea.set_text(text(b"secret\0"));
+        assert!(textarea.get_password_mode());
+        assert_eq!(textarea.get_text(), text(b"secret\0"));
+    }
+}